@@ -0,0 +1,134 @@
+use strum::{Display, EnumString, IntoEnumIterator};
+
+use crate::contract::Contract;
+use crate::errors::TarotErrorKind;
+
+/// Who deals again when everyone passed
+#[derive(Default, Display, EnumString, Debug, Copy, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Redeal {
+    // RULE: when everyone passes, the next player deals
+    #[default]
+    #[strum(serialize = "next")]
+    NextDealer,
+    #[strum(serialize = "same")]
+    SameDealer,
+}
+
+/// Bids of a deal, each seat speaks once starting from the player after the dealer
+#[derive(Default, Debug, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Auction {
+    players: usize,
+    first: usize,
+    bids: Vec<(usize, Option<Contract>)>,
+}
+
+impl Auction {
+    #[must_use]
+    pub const fn new(players: usize, dealer: usize) -> Self {
+        Self {
+            players,
+            first: (dealer + 1) % players,
+            bids: Vec::new(),
+        }
+    }
+    // seat which must speak now, None once everyone spoke
+    #[must_use]
+    pub fn current(&self) -> Option<usize> {
+        (self.bids.len() < self.players).then(|| (self.first + self.bids.len()) % self.players)
+    }
+    #[must_use]
+    pub fn is_finished(&self) -> bool {
+        self.current().is_none()
+    }
+    #[must_use]
+    pub fn highest(&self) -> Option<(usize, Contract)> {
+        self.bids
+            .iter()
+            .filter_map(|(seat, contract)| contract.map(|contract| (*seat, contract)))
+            .max_by_key(|(_, contract)| *contract)
+    }
+    // RULE: a bid must be higher than every previous one
+    #[must_use]
+    pub fn available(&self) -> Vec<Contract> {
+        let highest = self.highest().map(|(_, contract)| contract);
+        Contract::iter()
+            .filter(|contract| highest.is_none_or(|highest| *contract > highest))
+            .collect()
+    }
+    pub fn bid(&mut self, seat: usize, contract: Option<Contract>) -> Result<(), TarotErrorKind> {
+        if self.current() != Some(seat) {
+            return Err(TarotErrorKind::IllegalChoice(format!(
+                "seat {seat} cannot bid now"
+            )));
+        }
+        if let Some(contract) = contract
+            && !self.available().contains(&contract)
+        {
+            return Err(TarotErrorKind::IllegalChoice(format!(
+                "contract {contract} is not available"
+            )));
+        }
+        self.bids.push((seat, contract));
+        Ok(())
+    }
+    #[must_use]
+    pub fn history(&self) -> &[(usize, Option<Contract>)] {
+        &self.bids
+    }
+    // seats which passed before the taker spoke
+    #[must_use]
+    pub fn passed_before_taker(&self) -> Vec<usize> {
+        let Some((taker, _)) = self.highest() else {
+            return Vec::new();
+        };
+        self.bids
+            .iter()
+            .take_while(|(seat, _)| *seat != taker)
+            .filter(|(_, contract)| contract.is_none())
+            .map(|(seat, _)| *seat)
+            .collect()
+    }
+    // taker and contract once the auction is over
+    #[must_use]
+    pub fn taker(&self) -> Option<(usize, Contract)> {
+        if self.is_finished() {
+            self.highest()
+        } else {
+            None
+        }
+    }
+}
+
+#[test]
+fn auction_tests() -> Result<(), TarotErrorKind> {
+    let mut auction = Auction::new(4, 3);
+    assert_eq!(auction.current(), Some(0));
+    auction.bid(0, None)?;
+    auction.bid(1, Some(Contract::Garde))?;
+    assert_eq!(
+        auction.bid(2, Some(Contract::Petite)),
+        Err(TarotErrorKind::IllegalChoice(
+            "contract Petite is not available".to_string()
+        ))
+    );
+    assert_eq!(
+        auction.available(),
+        vec![Contract::GardeSans, Contract::GardeContre]
+    );
+    assert!(auction.bid(3, None).is_err());
+    auction.bid(2, None)?;
+    assert_eq!(auction.taker(), None);
+    auction.bid(3, None)?;
+    assert_eq!(auction.taker(), Some((1, Contract::Garde)));
+    assert_eq!(auction.passed_before_taker(), vec![0]);
+    assert_eq!(auction.history().len(), 4);
+
+    let mut everyone_passed = Auction::new(3, 0);
+    for seat in [1, 2, 0] {
+        everyone_passed.bid(seat, None)?;
+    }
+    assert_eq!(everyone_passed.taker(), None);
+    Ok(())
+}
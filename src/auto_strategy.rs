@@ -0,0 +1,105 @@
+use derive_new::new;
+use rand::rngs::StdRng;
+
+use crate::card::Card;
+use crate::contract::Contract;
+use crate::errors::TarotErrorKind;
+use crate::handle::Handle;
+use crate::misere::Misere;
+use crate::player::Player;
+use crate::player_in_game::PlayerInGame;
+use crate::strategy::PlayerStrategy;
+use crate::turn::Turn;
+
+/// Takes forced decisions alone and asks the fallback strategy for everything else
+#[derive(new, Debug)]
+pub struct AutoStrategy {
+    fallback: Box<dyn PlayerStrategy>,
+}
+
+impl PlayerStrategy for AutoStrategy {
+    fn bid(
+        &self,
+        player: &Player,
+        in_game: &PlayerInGame,
+        contracts: &[Contract],
+        rng: &mut StdRng,
+    ) -> Result<Option<Contract>, TarotErrorKind> {
+        if contracts.len() == 1 {
+            return Ok(None);
+        }
+        self.fallback.bid(player, in_game, contracts, rng)
+    }
+    fn slam(
+        &self,
+        player: &Player,
+        in_game: &PlayerInGame,
+        rng: &mut StdRng,
+    ) -> Result<bool, TarotErrorKind> {
+        self.fallback.slam(player, in_game, rng)
+    }
+    fn misere(
+        &self,
+        player: &Player,
+        in_game: &PlayerInGame,
+        misere: Misere,
+        rng: &mut StdRng,
+    ) -> Result<bool, TarotErrorKind> {
+        self.fallback.misere(player, in_game, misere, rng)
+    }
+    fn handle(
+        &self,
+        player: &Player,
+        in_game: &PlayerInGame,
+        trumps: &[Card],
+        handles: &[Handle],
+        rng: &mut StdRng,
+    ) -> Result<Handle, TarotErrorKind> {
+        self.fallback.handle(player, in_game, trumps, handles, rng)
+    }
+    fn hide_trump(
+        &self,
+        player: &Player,
+        in_game: &PlayerInGame,
+        trumps: &[Card],
+        limit: usize,
+        rng: &mut StdRng,
+    ) -> Result<usize, TarotErrorKind> {
+        self.fallback
+            .hide_trump(player, in_game, trumps, limit, rng)
+    }
+    fn call(
+        &self,
+        player: &Player,
+        in_game: &PlayerInGame,
+        choices: &[Card],
+        rng: &mut StdRng,
+    ) -> Result<Card, TarotErrorKind> {
+        self.fallback.call(player, in_game, choices, rng)
+    }
+    fn explain_discard(&self, in_game: &PlayerInGame) -> Option<String> {
+        self.fallback.explain_discard(in_game)
+    }
+    fn discard(
+        &self,
+        player: &Player,
+        in_game: &PlayerInGame,
+        discardables: &[usize],
+        rng: &mut StdRng,
+    ) -> Result<usize, TarotErrorKind> {
+        self.fallback.discard(player, in_game, discardables, rng)
+    }
+    fn play(
+        &self,
+        player: &Player,
+        in_game: &PlayerInGame,
+        turn: &Turn,
+        choices: &[usize],
+        rng: &mut StdRng,
+    ) -> Result<usize, TarotErrorKind> {
+        if let [only_choice] = choices {
+            return Ok(*only_choice);
+        }
+        self.fallback.play(player, in_game, turn, choices, rng)
+    }
+}
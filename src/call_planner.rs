@@ -0,0 +1,95 @@
+use crate::card::Card;
+use crate::contract::Contract;
+use crate::deck::Deck;
+use crate::hand_evaluation;
+use crate::mode::Mode;
+use crate::suit::Suit;
+use crate::suit_value::SuitValue;
+
+const QUEEN_SCORE: usize = 4;
+const KNIGHT_SCORE: usize = 2;
+const JACK_SCORE: usize = 1;
+const USEFUL_LENGTH: usize = 3;
+const LONG_SUIT: usize = 4;
+
+// a held honour gets protected by the called card, and some cards are needed to reach the partner
+fn suit_score(hand: &Deck, suit: Suit) -> usize {
+    let honours: usize = [
+        (SuitValue::Queen, QUEEN_SCORE),
+        (SuitValue::Knight, KNIGHT_SCORE),
+        (SuitValue::Jack, JACK_SCORE),
+    ]
+    .into_iter()
+    .filter(|(value, _)| hand.has(&Card::normal(suit, *value)))
+    .map(|(_, score)| score)
+    .sum();
+    let length = hand.count_suit(suit);
+    // the other players will cut a suit the taker is long in
+    (honours + length.min(USEFUL_LENGTH)).saturating_sub(length.saturating_sub(LONG_SUIT))
+}
+
+// RULE: calling a card of his own hand, the taker plays alone against the four others
+fn alone(hand: &Deck) -> bool {
+    hand_evaluation::evaluate(hand) >= hand_evaluation::threshold(Mode::Five, Contract::GardeContre)
+}
+
+// card to call among the choices, one of the taker's own when the hand is strong enough to play alone
+#[must_use]
+pub fn choose(hand: &Deck, choices: &[Card]) -> Option<Card> {
+    let (owned, others): (Vec<Card>, Vec<Card>) = choices.iter().partition(|card| hand.has(card));
+    let by_score = |card: &&Card| match card {
+        Card::Normal(normal) => suit_score(hand, *normal.suit()),
+        Card::Trump(_) => 0,
+    };
+    if (alone(hand) || others.is_empty())
+        && let Some(card) = owned.iter().max_by_key(by_score)
+    {
+        return Some(*card);
+    }
+    others.iter().max_by_key(by_score).copied()
+}
+
+#[test]
+fn call_planner_tests() {
+    use crate::trump::Trump;
+
+    let kings: Vec<Card> = [Suit::Heart, Suit::Spade, Suit::Diamond, Suit::Club]
+        .into_iter()
+        .map(|suit| Card::normal(suit, SuitValue::King))
+        .collect();
+    let hand = Deck::new(vec![
+        Card::Trump(Trump::_12),
+        Card::Trump(Trump::_6),
+        Card::normal(Suit::Heart, SuitValue::King),
+        Card::normal(Suit::Spade, SuitValue::Queen),
+        Card::normal(Suit::Spade, SuitValue::_4),
+        Card::normal(Suit::Diamond, SuitValue::_2),
+        Card::normal(Suit::Diamond, SuitValue::_5),
+        Card::normal(Suit::Diamond, SuitValue::_7),
+        Card::normal(Suit::Club, SuitValue::_9),
+    ]);
+    // the king protecting the queen is the best partner
+    assert_eq!(
+        choose(&hand, &kings),
+        Some(Card::normal(Suit::Spade, SuitValue::King))
+    );
+
+    let strong = Deck::new(vec![
+        Card::Trump(Trump::Fool),
+        Card::Trump(Trump::Petit),
+        Card::Trump(Trump::_21),
+        Card::Trump(Trump::_20),
+        Card::Trump(Trump::_19),
+        Card::Trump(Trump::_18),
+        Card::Trump(Trump::_16),
+        Card::Trump(Trump::_14),
+        Card::Trump(Trump::_11),
+        Card::Trump(Trump::_8),
+        Card::normal(Suit::Heart, SuitValue::King),
+        Card::normal(Suit::Heart, SuitValue::Queen),
+        Card::normal(Suit::Spade, SuitValue::King),
+        Card::normal(Suit::Diamond, SuitValue::King),
+        Card::normal(Suit::Club, SuitValue::_3),
+    ]);
+    assert!(choose(&strong, &kings).is_some_and(|card| strong.has(&card)));
+}
@@ -1,11 +1,11 @@
+use crate::errors::TarotErrorKind;
 use crate::normal::Normal;
-use crate::points::Points;
+use crate::points::{HalfPoints, Points};
 use crate::suit::Suit;
 use crate::suit_value::SuitValue;
-use crate::traits::{Discardable, Power, Representation};
+use crate::traits::{Code, Discardable, Power, Representation};
 use crate::trump::Trump;
 use colored::ColoredString;
-use ordered_float::OrderedFloat;
 use std::fmt;
 
 #[derive(Copy, Ord, Clone, Debug, Eq, PartialEq, PartialOrd)]
@@ -24,7 +24,7 @@ impl fmt::Display for Card {
 }
 
 impl Points for Card {
-    fn points(&self) -> OrderedFloat<f64> {
+    fn points(&self) -> HalfPoints {
         match self {
             Self::Trump(v) => v.points(),
             Self::Normal(n) => n.points(),
@@ -89,6 +89,20 @@ impl Card {
     }
 }
 
+impl Code for Card {
+    fn code(&self) -> String {
+        match self {
+            Self::Trump(t) => t.code(),
+            Self::Normal(n) => n.code(),
+        }
+    }
+    fn from_code(code: &str) -> Result<Self, TarotErrorKind> {
+        Trump::from_code(code)
+            .map(Self::Trump)
+            .or_else(|_| Normal::from_code(code).map(Self::Normal))
+    }
+}
+
 impl Representation for Card {
     fn symbol(&self) -> &'static str {
         match self {
@@ -124,6 +138,8 @@ impl Representation for Card {
 
 #[test]
 fn card_tests() {
+    use crate::deck::Deck;
+
     let trump_2 = Card::Trump(Trump::_2);
     println!("{}", trump_2.repr());
     let petit = Card::Trump(Trump::Petit);
@@ -152,11 +168,24 @@ fn card_tests() {
     assert!(diamond_3.master(fool));
     assert!(!fool.master(spade_1));
 
+    assert_eq!(fool.code(), "F");
+    assert_eq!(petit.code(), "T1");
+    assert_eq!(spade_10.code(), "10S");
+    assert_eq!(club_king.code(), "KC");
+    assert_eq!(Card::from_code("T21"), Ok(unassailable));
+    assert_eq!(Card::from_code("3D"), Ok(diamond_3));
+    for card in Deck::full().iter() {
+        assert_eq!(Card::from_code(&card.code()).as_ref(), Ok(card));
+    }
+    assert!(Card::from_code("T22").is_err());
+    assert!(Card::from_code("11H").is_err());
+    assert!(Card::from_code("").is_err());
+
     assert!(!petit.discardable());
     assert!(!fool.discardable());
     assert!(!unassailable.discardable());
     assert!(!petit.discardable_forced());
     assert!(!fool.discardable_forced());
     assert!(!unassailable.discardable_forced());
-    assert_eq!(unassailable.points(), 4.5);
+    assert_eq!(unassailable.points(), HalfPoints::from_halves(9));
 }
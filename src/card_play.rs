@@ -0,0 +1,340 @@
+use crate::card::Card;
+use crate::errors::TarotErrorKind;
+use crate::player_in_game::PlayerInGame;
+use crate::points::{HalfPoints, Points};
+use crate::suit_value::SuitValue;
+use crate::traits::Power;
+use crate::trump::Trump;
+use crate::turn::Turn;
+
+const SAFE_TRUMP: Trump = Trump::_16;
+
+// value of a card we do not want to lose, the petit counts as the most precious one
+fn keep_value(card: Card) -> (HalfPoints, usize) {
+    let points = if card == Card::Trump(Trump::Petit) {
+        HalfPoints::from_halves(i32::MAX)
+    } else {
+        card.points()
+    };
+    (points, card.power())
+}
+
+fn is_partner(in_game: &PlayerInGame, turn: &Turn, player_index: usize) -> bool {
+    let Some(taker_index) = in_game.taker_index() else {
+        return false;
+    };
+    // RULE: nobody knows the ally before the called card is played
+    let revealed_ally = in_game.knowledge().ally() == Some(player_index)
+        || in_game
+            .callee()
+            .is_some_and(|callee| turn.played_by(&callee) == Some(player_index));
+    if in_game.is_taker() {
+        return revealed_ally && player_index != taker_index;
+    }
+    if in_game.is_attack() {
+        return player_index == taker_index;
+    }
+    player_index != taker_index && !revealed_ally
+}
+
+fn beats(master: Card, card: Card) -> bool {
+    !card.is_fool() && !master.master(card)
+}
+
+// index of the card to play among legal choices, following standard tarot practice
+pub fn play(
+    in_game: &PlayerInGame,
+    turn: &Turn,
+    choices: &[usize],
+) -> Result<usize, TarotErrorKind> {
+    let hand = in_game.hand();
+    let candidates: Vec<(usize, Card)> = choices
+        .iter()
+        .filter_map(|index| hand.get(*index).map(|card| (*index, *card)))
+        .collect();
+    let Some(&(first_index, _)) = candidates.first() else {
+        return Err(TarotErrorKind::NoCard(0));
+    };
+    let fool = candidates.iter().find(|(_, card)| card.is_fool()).copied();
+
+    // RULE: the fool is lost if kept for the last turn
+    if let (Some((fool_index, _)), 2) = (fool, hand.len()) {
+        return Ok(fool_index);
+    }
+    let others: Vec<(usize, Card)> = candidates
+        .iter()
+        .filter(|(_, card)| !card.is_fool())
+        .copied()
+        .collect();
+    if others.is_empty() {
+        return Ok(first_index);
+    }
+
+    let (Some(master), Some(master_player)) = (turn.master_card(), turn.winner()) else {
+        return Ok(lead(in_game, &others).unwrap_or(first_index));
+    };
+    let master = *master;
+    let last_to_play = turn.len() + 1 == in_game.mode().players();
+    let partner_wins = is_partner(in_game, turn, master_player);
+    let knowledge = in_game.knowledge();
+    let master_holds = last_to_play
+        || turn.is_won_by_fool()
+        || matches!(master, Card::Trump(trump) if trump >= SAFE_TRUMP || knowledge.higher_trumps(trump, hand) == 0);
+
+    if partner_wins && master_holds {
+        // charger : give points to our own trick
+        return Ok(others
+            .iter()
+            .filter(|(_, card)| {
+                last_to_play || (*card != Card::Trump(Trump::Petit) && !card.is_trump())
+            })
+            .max_by_key(|(_, card)| (card.points(), std::cmp::Reverse(card.power())))
+            .or_else(|| others.iter().min_by_key(|(_, card)| keep_value(*card)))
+            .map_or(first_index, |(index, _)| *index));
+    }
+
+    let winners: Vec<(usize, Card)> = others
+        .iter()
+        .filter(|(_, card)| beats(master, *card))
+        .copied()
+        .collect();
+    if !partner_wins && !winners.is_empty() {
+        let winner = if last_to_play {
+            // cheapest card which takes the turn, the petit is safe here
+            winners.iter().min_by_key(|(_, card)| card.power())
+        } else {
+            // over-trump cheaply, but never risk the petit
+            winners
+                .iter()
+                .filter(|(_, card)| *card != Card::Trump(Trump::Petit))
+                .min_by_key(|(_, card)| match card {
+                    Card::Trump(_) => card.power(),
+                    Card::Normal(_) => usize::MAX - card.power(),
+                })
+        };
+        if let Some((index, _)) = winner {
+            return Ok(*index);
+        }
+    }
+
+    let (lowest_index, lowest) = others
+        .iter()
+        .min_by_key(|(_, card)| keep_value(*card))
+        .copied()
+        .unwrap_or((first_index, Card::Trump(Trump::Fool)));
+    // RULE: the fool saves a card with points from opponents
+    if let Some((fool_index, _)) = fool
+        && !partner_wins
+        && (lowest.points() > HalfPoints::HALF || lowest == Card::Trump(Trump::Petit))
+    {
+        return Ok(fool_index);
+    }
+    Ok(lowest_index)
+}
+
+fn lead(in_game: &PlayerInGame, others: &[(usize, Card)]) -> Option<usize> {
+    let hand = in_game.hand();
+    // the petit cannot be taken once every other trump is gone
+    if in_game.knowledge().remaining_trumps(hand) == 0
+        && let Some((index, _)) = others
+            .iter()
+            .find(|(_, card)| *card == Card::Trump(Trump::Petit))
+    {
+        return Some(*index);
+    }
+    let trumps: Vec<(usize, Card)> = others
+        .iter()
+        .filter(|(_, card)| card.is_trump())
+        .copied()
+        .collect();
+
+    // taker with long trumps pulls the trumps of the defense, from the top
+    if in_game.is_taker()
+        && trumps.len() * 2 >= hand.len()
+        && let Some((index, _)) = trumps
+            .iter()
+            .filter(|(_, card)| *card != Card::Trump(Trump::Petit))
+            .max_by_key(|(_, card)| card.power())
+    {
+        return Some(*index);
+    }
+
+    // kings first, in their shortest suit
+    let kings = others.iter().filter(|(_, card)| match card {
+        Card::Normal(normal) => normal.value() == &SuitValue::King,
+        Card::Trump(_) => false,
+    });
+    if let Some((index, _)) = kings.min_by_key(|(_, card)| match card {
+        Card::Normal(normal) => hand.count_suit(*normal.suit()),
+        Card::Trump(_) => usize::MAX,
+    }) {
+        return Some(*index);
+    }
+
+    // then a small card of our longest suit, keeping the petit
+    let small = others
+        .iter()
+        .filter(|(_, card)| !card.is_trump())
+        .min_by_key(|(_, card)| {
+            let length = match card {
+                Card::Normal(normal) => hand.count_suit(*normal.suit()),
+                Card::Trump(_) => 0,
+            };
+            (card.points(), std::cmp::Reverse(length), card.power())
+        })
+        .or_else(|| {
+            others
+                .iter()
+                .filter(|(_, card)| *card != Card::Trump(Trump::Petit))
+                .min_by_key(|(_, card)| card.power())
+        })
+        .or_else(|| others.first())?;
+    Some(small.0)
+}
+
+#[test]
+fn card_play_tests() -> Result<(), TarotErrorKind> {
+    use crate::card::Card;
+    use crate::contract::Contract;
+    use crate::game::Game;
+    use crate::handle::Handle;
+    use crate::heuristic_strategy::HeuristicStrategy;
+    use crate::misere::Misere;
+    use crate::mode::Mode;
+    use crate::options::Options;
+    use crate::player::Player;
+    use crate::random_strategy::RandomStrategy;
+    use crate::strategy::PlayerStrategy;
+    use rand::rngs::StdRng;
+
+    // every seat bids, discards and announces like the heuristic bot, only the card play differs
+    #[derive(Debug)]
+    struct PlayOnly {
+        others: HeuristicStrategy,
+        player: Box<dyn PlayerStrategy>,
+    }
+    impl PlayerStrategy for PlayOnly {
+        fn bid(
+            &self,
+            player: &Player,
+            in_game: &PlayerInGame,
+            contracts: &[Contract],
+            rng: &mut StdRng,
+        ) -> Result<Option<Contract>, TarotErrorKind> {
+            self.others.bid(player, in_game, contracts, rng)
+        }
+        fn slam(
+            &self,
+            player: &Player,
+            in_game: &PlayerInGame,
+            rng: &mut StdRng,
+        ) -> Result<bool, TarotErrorKind> {
+            self.others.slam(player, in_game, rng)
+        }
+        fn misere(
+            &self,
+            player: &Player,
+            in_game: &PlayerInGame,
+            misere: Misere,
+            rng: &mut StdRng,
+        ) -> Result<bool, TarotErrorKind> {
+            self.others.misere(player, in_game, misere, rng)
+        }
+        fn handle(
+            &self,
+            player: &Player,
+            in_game: &PlayerInGame,
+            trumps: &[Card],
+            handles: &[Handle],
+            rng: &mut StdRng,
+        ) -> Result<Handle, TarotErrorKind> {
+            self.others.handle(player, in_game, trumps, handles, rng)
+        }
+        fn hide_trump(
+            &self,
+            player: &Player,
+            in_game: &PlayerInGame,
+            trumps: &[Card],
+            limit: usize,
+            rng: &mut StdRng,
+        ) -> Result<usize, TarotErrorKind> {
+            self.others.hide_trump(player, in_game, trumps, limit, rng)
+        }
+        fn call(
+            &self,
+            player: &Player,
+            in_game: &PlayerInGame,
+            choices: &[Card],
+            rng: &mut StdRng,
+        ) -> Result<Card, TarotErrorKind> {
+            self.others.call(player, in_game, choices, rng)
+        }
+        fn discard(
+            &self,
+            player: &Player,
+            in_game: &PlayerInGame,
+            discardables: &[usize],
+            rng: &mut StdRng,
+        ) -> Result<usize, TarotErrorKind> {
+            self.others.discard(player, in_game, discardables, rng)
+        }
+        fn play(
+            &self,
+            player: &Player,
+            in_game: &PlayerInGame,
+            turn: &Turn,
+            choices: &[usize],
+            rng: &mut StdRng,
+        ) -> Result<usize, TarotErrorKind> {
+            self.player.play(player, in_game, turn, choices, rng)
+        }
+    }
+
+    const DEALS: u32 = 300;
+    // the heuristic play has to win at least this much
+    const MIN_MARGIN: f64 = 10.0;
+    // mean score of a heuristic player minus the one of a random player, per deal
+    fn margin<const MODE: usize>(options: Options) -> Result<f64, TarotErrorKind> {
+        let strategies: [Box<dyn PlayerStrategy>; MODE] = array_init::array_init(|i| {
+            let player: Box<dyn PlayerStrategy> = if i % 2 == 0 {
+                Box::new(HeuristicStrategy::new(false))
+            } else {
+                Box::new(RandomStrategy::new(false))
+            };
+            Box::new(PlayOnly {
+                others: HeuristicStrategy::new(false),
+                player,
+            }) as Box<dyn PlayerStrategy>
+        });
+        let mut game = Game::<MODE>::with_strategies(options, 7, strategies)?;
+        game.start(u64::from(DEALS))?;
+        let mode: Mode = MODE.try_into()?;
+        let mut heuristic = Vec::new();
+        let mut random = Vec::new();
+        for player in game.players() {
+            let seat = (0..MODE)
+                .find(|i| mode.player_name(*i) == Ok(player.name()))
+                .ok_or(TarotErrorKind::NoPlayer(0))?;
+            if seat % 2 == 0 {
+                heuristic.push(f64::from(player.score()));
+            } else {
+                random.push(f64::from(player.score()));
+            }
+        }
+        let mean = |scores: &[f64]| {
+            scores.iter().sum::<f64>() / f64::from(u32::try_from(scores.len()).unwrap_or(u32::MAX))
+        };
+        let margin = (mean(&heuristic) - mean(&random)) / f64::from(DEALS);
+        println!("{mode} : {margin:.1} points per deal");
+        Ok(margin)
+    }
+    let options = Options {
+        quiet: true,
+        test: true,
+        ..Options::default()
+    };
+    assert!(margin::<{ Mode::Three.players() }>(options)? > MIN_MARGIN);
+    assert!(margin::<{ Mode::Four.players() }>(options)? > MIN_MARGIN);
+    assert!(margin::<{ Mode::Five.players() }>(options)? > MIN_MARGIN);
+    Ok(())
+}
@@ -1,6 +1,6 @@
-use ordered_float::OrderedFloat;
+use crate::points::HalfPoints;
 
 pub const MAX_CARDS: usize = 78;
-pub const BASE_CONTRACT_POINTS: OrderedFloat<f64> = OrderedFloat(25.0);
-pub const MAX_POINTS: OrderedFloat<f64> = OrderedFloat(91.0);
-pub const MAX_POINTS_WITHOUT_FOOL: OrderedFloat<f64> = OrderedFloat(87.0);
+pub const TAROCK_CARDS: usize = 54;
+pub const MAX_POINTS: HalfPoints = HalfPoints::from_points(91);
+pub const MAX_POINTS_WITHOUT_FOOL: HalfPoints = HalfPoints::from_points(87);
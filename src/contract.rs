@@ -1,6 +1,7 @@
 use strum::{Display, EnumIter};
 
 #[derive(Display, Copy, Clone, Debug, Eq, Ord, PartialEq, PartialOrd, EnumIter)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Contract {
     Petite,
     Garde,
@@ -9,15 +10,3 @@ pub enum Contract {
     #[strum(serialize = "Garde Contre")]
     GardeContre,
 }
-
-impl Contract {
-    #[must_use]
-    pub const fn multiplier(self) -> f64 {
-        match self {
-            Self::Petite => 1.0,
-            Self::Garde => 2.0,
-            Self::GardeSans => 4.0,
-            Self::GardeContre => 6.0,
-        }
-    }
-}
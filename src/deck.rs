@@ -2,7 +2,7 @@ use colored::{ColoredString, Colorize};
 use derive_more::{Deref, Index, IntoIterator};
 use derive_new::new;
 use itertools::Itertools;
-use ordered_float::OrderedFloat;
+use rand::rngs::StdRng;
 use rand::seq::SliceRandom;
 use std::collections::BTreeMap;
 use std::fmt;
@@ -11,29 +11,88 @@ use strum::IntoEnumIterator;
 use crate::card::Card;
 use crate::constants::{MAX_CARDS, MAX_POINTS, MAX_POINTS_WITHOUT_FOOL};
 use crate::errors::TarotErrorKind;
-use crate::points::Points;
+use crate::mode::Mode;
+use crate::points::{HalfPoints, Points};
+use crate::rules::RuleSet;
 use crate::suit::Suit;
 use crate::suit_value::SuitValue;
 use crate::traits::{Discardable, Representation};
 use crate::trump::Trump;
 
 #[derive(new, Default, PartialEq, Eq, Clone, Debug, IntoIterator, Index, Deref)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[deref(forward)]
 pub struct Deck(Vec<Card>);
 
 impl Deck {
-    pub fn random() -> Self {
-        let mut d: Vec<Card> = Trump::iter()
-            .map(Card::Trump)
-            .chain(
-                Suit::iter()
-                    .cartesian_product(SuitValue::iter())
-                    .map(|(c, cv)| Card::normal(c, cv)),
-            )
-            .collect();
-        let mut rng = rand::rng();
-        d.shuffle(&mut rng);
-        Self(d)
+    #[must_use]
+    pub fn full() -> Self {
+        Self(
+            Trump::iter()
+                .map(Card::Trump)
+                .chain(
+                    Suit::iter()
+                        .cartesian_product(SuitValue::iter())
+                        .map(|(c, cv)| Card::normal(c, cv)),
+                )
+                .collect(),
+        )
+    }
+    // RULE: the 54 cards of tarock, red suits keep their four lowest pips and black suits their four highest
+    #[must_use]
+    pub fn tarock() -> Self {
+        Self(
+            Self::full()
+                .0
+                .into_iter()
+                .filter(|card| match card {
+                    Card::Trump(_) => true,
+                    Card::Normal(normal) => match normal.suit() {
+                        Suit::Heart | Suit::Diamond => {
+                            normal.value() <= &SuitValue::_4 || normal.value() >= &SuitValue::Jack
+                        }
+                        Suit::Spade | Suit::Club => normal.value() >= &SuitValue::_7,
+                    },
+                })
+                .collect(),
+        )
+    }
+    pub fn random(rng: &mut StdRng) -> Self {
+        let mut d = Self::full();
+        d.shuffle(rng);
+        d
+    }
+    pub fn shuffle(&mut self, rng: &mut StdRng) {
+        self.0.shuffle(rng);
+    }
+    // RULE: packets go around from the player after the dealer, the dog takes single cards between packets
+    pub fn deal(
+        mut self,
+        mode: Mode,
+        rng: &mut StdRng,
+    ) -> Result<(Vec<Self>, Self), TarotErrorKind> {
+        if self.len() != MAX_CARDS {
+            return Err(TarotErrorKind::InvalidDeck(self));
+        }
+        let players = mode.players();
+        let packet = mode.cards_per_turn();
+        let packets = players * mode.cards_per_player() / packet;
+        // never before the first packet nor after the last one
+        let mut slots: Vec<usize> = (1..packets).collect();
+        slots.shuffle(rng);
+        slots.truncate(mode.dog_size());
+        let mut hands = vec![Self::default(); players];
+        let mut dog = Self::default();
+        for index in 0..packets {
+            if slots.contains(&index) {
+                dog.extend(&self.give(1));
+            }
+            let hand = hands
+                .get_mut(index % players)
+                .ok_or(TarotErrorKind::NoPlayer(index % players))?;
+            hand.extend(&self.give(packet));
+        }
+        Ok((hands, dog))
     }
     #[must_use]
     pub fn trumps_and_colors(&self) -> (Vec<Card>, Vec<Card>) {
@@ -64,17 +123,10 @@ impl Deck {
         // RULE: deck is a chelem if all cards are there or fool is missing
         self.points() == MAX_POINTS || self.points() == MAX_POINTS_WITHOUT_FOOL
     }
-    pub fn points_for_oudlers(&self) -> Result<OrderedFloat<f64>, TarotErrorKind> {
-        match self.count_oudlers() {
-            0 => Ok(OrderedFloat(56.0)),
-            1 => Ok(OrderedFloat(51.0)),
-            2 => Ok(OrderedFloat(41.0)),
-            3 => Ok(OrderedFloat(36.0)),
-            _ => {
-                let only_oudlers = Self(self.oudlers());
-                Err(TarotErrorKind::InvalidOudlersCount(only_oudlers))
-            }
-        }
+    pub fn points_for_oudlers(&self, rules: &RuleSet) -> Result<HalfPoints, TarotErrorKind> {
+        rules
+            .needed(self.count_oudlers())
+            .ok_or_else(|| TarotErrorKind::InvalidOudlersCount(Self(self.oudlers())))
     }
     #[must_use]
     pub const fn len(&self) -> usize {
@@ -134,12 +186,27 @@ impl Deck {
             .count()
     }
     #[must_use]
+    pub fn count_suit(&self, suit: Suit) -> usize {
+        self.iter()
+            .filter(|card| match card {
+                Card::Normal(n) => n.suit() == &suit,
+                Card::Trump(_) => false,
+            })
+            .count()
+    }
+    // RULE: a misère de tête is a hand without king, queen, knight or jack
+    #[must_use]
     pub fn misere_tete(&self) -> bool {
         !self.iter().any(|card| match card {
-            Card::Normal(n) => n.points() == 0.5,
+            Card::Normal(n) => n.points() > HalfPoints::HALF,
             Card::Trump(_) => false,
         })
     }
+    // RULE: a misère d'atout is a hand without trump, the fool is allowed
+    #[must_use]
+    pub fn misere_atout(&self) -> bool {
+        !self.iter().any(|card| card.is_trump() && !card.is_fool())
+    }
     #[must_use]
     pub fn give(&mut self, size: usize) -> Self {
         Self(self.0.drain(0..size).collect())
@@ -148,13 +215,6 @@ impl Deck {
     pub fn give_all(&mut self) -> Self {
         Self(self.0.drain(..).collect())
     }
-    pub fn give_low(&mut self) -> Option<Card> {
-        self.iter()
-            .enumerate()
-            .find_map(|(i, c)| if c.points() == 0.5 { Some(i) } else { None })
-            .as_ref()
-            .map(|index| self.remove(index.to_owned()))
-    }
     pub fn remove(&mut self, index: usize) -> Card {
         self.0.remove(index)
     }
@@ -210,27 +270,44 @@ impl fmt::Display for Deck {
 }
 
 impl Points for Deck {
-    fn points(&self) -> OrderedFloat<f64> {
+    fn points(&self) -> HalfPoints {
         // RULE: if a slam is occuring and player has only fool or everyting except fool, fool = 4 points
         if self.len() == MAX_CARDS - 1 && !self.has_fool() {
             MAX_POINTS_WITHOUT_FOOL
         } else if self.only_fool() {
-            OrderedFloat(4.0)
+            HalfPoints::from_points(4)
         } else {
-            let mut total = OrderedFloat(0.0);
-            for card in self.iter() {
-                total += card.points();
-            }
-            total
+            self.iter().map(Points::points).sum()
         }
     }
 }
 
 #[test]
-fn deck_tests() {
-    let deck = Deck::random();
+fn deck_tests() -> Result<(), TarotErrorKind> {
+    use rand::SeedableRng;
+    let deck = Deck::random(&mut StdRng::seed_from_u64(42));
     assert_eq!(deck.len(), MAX_CARDS);
     assert_eq!(deck.points(), MAX_POINTS);
+    assert_eq!(deck, Deck::random(&mut StdRng::seed_from_u64(42)));
+
+    let (hands, dog) = deck
+        .clone()
+        .deal(Mode::Four, &mut StdRng::seed_from_u64(42))?;
+    assert_eq!(dog.len(), Mode::Four.dog_size());
+    assert!(
+        hands
+            .iter()
+            .all(|hand| hand.len() == Mode::Four.cards_per_player())
+    );
+    // first and last packets are never split by the dog
+    assert_eq!(hands.first().map(|hand| hand.first()), Some(deck.first()));
+    assert_eq!(hands.last().map(|hand| hand.last()), Some(deck.last()));
+
+    let tarock = Deck::tarock();
+    assert_eq!(tarock.len(), crate::constants::TAROCK_CARDS);
+    assert_eq!(tarock.trumps().len(), 22);
+    assert!(!tarock.has(&Card::normal(Suit::Heart, SuitValue::_5)));
+    assert!(!tarock.has(&Card::normal(Suit::Club, SuitValue::_6)));
 
     let empty = Deck::default();
     assert!(empty.is_empty());
@@ -245,4 +322,5 @@ fn deck_tests() {
     ];
     let test_stack = Deck(two_cards);
     println!("{}", test_stack.full_repr());
+    Ok(())
 }
@@ -0,0 +1,23 @@
+use strum::{Display, EnumString};
+
+/// Way the turn goes around the table, for the deal and for the play
+#[derive(Default, Display, EnumString, Debug, Copy, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[strum(serialize_all = "lowercase")]
+pub enum Direction {
+    // RULE: the deal and the play go counterclockwise
+    #[default]
+    CounterClockwise,
+    Clockwise,
+}
+
+impl Direction {
+    // index of the default name for the seat playing at this place in the turn
+    #[must_use]
+    pub const fn seat(self, index: usize, players: usize) -> usize {
+        match self {
+            Self::CounterClockwise => index,
+            Self::Clockwise => (players - index) % players,
+        }
+    }
+}
@@ -0,0 +1,255 @@
+use itertools::Itertools;
+use std::cmp::Ordering;
+use std::fmt;
+use std::iter;
+use strum::IntoEnumIterator;
+
+use crate::card::Card;
+use crate::deck::Deck;
+use crate::points::{HalfPoints, Points};
+use crate::suit::Suit;
+use crate::suit_value::SuitValue;
+use crate::traits::Power;
+
+const VOID_SCORE: f64 = 8.0;
+const SINGLETON_SCORE: f64 = 3.0;
+const EXPOSED_HONOUR_PENALTY: f64 = 3.0;
+const FORCED_TRUMP_PENALTY: f64 = 10.0;
+
+/// Best discard found for a hand, with the reasons of the choice
+#[derive(Debug, Clone, PartialEq)]
+pub struct DiscardPlan {
+    cards: Deck,
+    voids: Vec<Suit>,
+    singletons: Vec<Suit>,
+    exposed: Vec<Card>,
+    score: f64,
+}
+
+impl DiscardPlan {
+    #[must_use]
+    pub const fn cards(&self) -> &Deck {
+        &self.cards
+    }
+    // on a tie, the lowest cards go to the discard
+    fn better_than(&self, other: &Self) -> bool {
+        let power = |plan: &Self| plan.cards.iter().map(Power::power).sum::<usize>();
+        match self.score.total_cmp(&other.score) {
+            Ordering::Greater => true,
+            Ordering::Less => false,
+            Ordering::Equal => power(self) < power(other),
+        }
+    }
+    fn evaluate(hand: &Deck, discard: Deck) -> Self {
+        let kept = |card: &Card| hand.has(card) && !discard.has(card);
+        let mut voids = Vec::new();
+        let mut singletons = Vec::new();
+        let mut exposed = Vec::new();
+        for suit in Suit::iter() {
+            let king = kept(&Card::normal(suit, SuitValue::King));
+            let length = hand.count_suit(suit);
+            match (length, length - discard.count_suit(suit)) {
+                (0, _) => {}
+                (_, 0) => voids.push(suit),
+                (_, 1) if !king => singletons.push(suit),
+                _ => {}
+            }
+            // RULE: without the king, a queen or a knight is often taken
+            if !king {
+                exposed.extend(
+                    [SuitValue::Queen, SuitValue::Knight]
+                        .into_iter()
+                        .map(|value| Card::normal(suit, value))
+                        .filter(kept),
+                );
+            }
+        }
+        // points in the discard are safe for the taker
+        let bonuses: f64 = voids
+            .iter()
+            .map(|_| VOID_SCORE)
+            .chain(singletons.iter().map(|_| SINGLETON_SCORE))
+            .sum();
+        let penalties: f64 = exposed
+            .iter()
+            .map(|_| EXPOSED_HONOUR_PENALTY)
+            .chain(discard.trumps().iter().map(|_| FORCED_TRUMP_PENALTY))
+            .sum();
+        let score = discard.points().to_f64() + bonuses - penalties;
+        Self {
+            cards: discard,
+            voids,
+            singletons,
+            exposed,
+            score,
+        }
+    }
+}
+
+impl fmt::Display for DiscardPlan {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "Discard {} : {} points kept",
+            self.cards.iter().join(" "),
+            self.cards.points()
+        )?;
+        if !self.voids.is_empty() {
+            write!(f, ", void in {}", self.voids.iter().join(" "))?;
+        }
+        if !self.singletons.is_empty() {
+            write!(f, ", singleton in {}", self.singletons.iter().join(" "))?;
+        }
+        if !self.exposed.is_empty() {
+            write!(f, ", still exposed {}", self.exposed.iter().join(" "))?;
+        }
+        let trumps = self.cards.trumps();
+        if !trumps.is_empty() {
+            write!(f, ", forced to show {}", trumps.iter().join(" "))?;
+        }
+        Ok(())
+    }
+}
+
+// ways to discard from one suit or from the trumps : any set of honours, and the lowest cards first
+fn options(cards: &[Card]) -> Vec<Vec<Card>> {
+    let (mut low, honours): (Vec<Card>, Vec<Card>) = cards
+        .iter()
+        .partition(|card| card.points() == HalfPoints::HALF);
+    low.sort_by_key(Power::power);
+    let low = &low;
+    honours
+        .into_iter()
+        .powerset()
+        .flat_map(|subset| {
+            (0..=low.len()).map(move |length| {
+                let mut option: Vec<Card> = low.iter().take(length).copied().collect();
+                option.extend(&subset);
+                option
+            })
+        })
+        .collect()
+}
+
+fn search(
+    hand: &Deck,
+    groups: &[Vec<Vec<Card>>],
+    left: usize,
+    chosen: &mut Vec<Card>,
+    best: &mut Option<DiscardPlan>,
+) {
+    let Some((options, rest)) = groups.split_first() else {
+        if left == 0 {
+            let mut cards = Deck::new(chosen.clone());
+            cards.sort();
+            let plan = DiscardPlan::evaluate(hand, cards);
+            if best.as_ref().is_none_or(|best| plan.better_than(best)) {
+                *best = Some(plan);
+            }
+        }
+        return;
+    };
+    for option in options.iter().filter(|option| option.len() <= left) {
+        chosen.extend(option);
+        search(hand, rest, left - option.len(), chosen, best);
+        chosen.truncate(chosen.len() - option.len());
+    }
+}
+
+// search among the legal discards of this size the one with the best score
+#[must_use]
+pub fn plan(hand: &Deck, count: usize) -> Option<DiscardPlan> {
+    let discardables: Vec<Card> = hand
+        .discardables(count)
+        .into_iter()
+        .filter_map(|index| hand.get(index).copied())
+        .collect();
+    let groups: Vec<Vec<Vec<Card>>> = Suit::iter()
+        .map(|suit| {
+            discardables
+                .iter()
+                .filter(|card| matches!(card, Card::Normal(normal) if normal.suit() == &suit))
+                .copied()
+                .collect::<Vec<Card>>()
+        })
+        .chain(iter::once(
+            discardables
+                .iter()
+                .filter(|card| card.is_trump())
+                .copied()
+                .collect(),
+        ))
+        .map(|cards| options(&cards))
+        .collect();
+    let mut best = None;
+    search(hand, &groups, count, &mut Vec::new(), &mut best);
+    best
+}
+
+// RULE: the card given back for the excuse is a low card of the owner's tricks or discard
+pub fn give_back(owned: &mut Deck, discard: &mut Deck) -> Option<Card> {
+    let low = |deck: &Deck| {
+        deck.iter()
+            .enumerate()
+            .filter(|(_, card)| card.points() == HalfPoints::HALF)
+            .min_by_key(|(_, card)| card.power())
+            .map(|(index, _)| index)
+    };
+    if let Some(index) = low(discard) {
+        return Some(discard.remove(index));
+    }
+    low(owned).map(|index| owned.remove(index))
+}
+
+#[test]
+fn discard_planner_tests() -> Result<(), crate::errors::TarotErrorKind> {
+    use crate::errors::TarotErrorKind;
+    use crate::trump::Trump;
+
+    let hand = Deck::new(vec![
+        Card::Trump(Trump::_21),
+        Card::Trump(Trump::_15),
+        Card::Trump(Trump::_8),
+        Card::Trump(Trump::_3),
+        Card::normal(Suit::Heart, SuitValue::King),
+        Card::normal(Suit::Heart, SuitValue::_4),
+        Card::normal(Suit::Heart, SuitValue::_2),
+        Card::normal(Suit::Spade, SuitValue::Queen),
+        Card::normal(Suit::Spade, SuitValue::_7),
+        Card::normal(Suit::Club, SuitValue::_9),
+        Card::normal(Suit::Club, SuitValue::_5),
+        Card::normal(Suit::Club, SuitValue::_1),
+    ]);
+    let best = plan(&hand, 3).ok_or_else(|| TarotErrorKind::InvalidDeck(hand.clone()))?;
+    // the lonely queen is kept safe and a color is cut
+    assert!(
+        best.cards()
+            .has(&Card::normal(Suit::Spade, SuitValue::Queen))
+    );
+    assert!(best.cards().has(&Card::normal(Suit::Spade, SuitValue::_7)));
+    assert_eq!(best.cards().count_trumps(), 0);
+
+    // only trumps and kings left : trumps are forced, never the oudlers
+    let kings = Deck::new(vec![
+        Card::Trump(Trump::_21),
+        Card::Trump(Trump::_5),
+        Card::Trump(Trump::_2),
+        Card::normal(Suit::Heart, SuitValue::King),
+        Card::normal(Suit::Spade, SuitValue::_3),
+    ]);
+    assert_eq!(
+        plan(&kings, 2).map(|plan| plan.cards().clone()),
+        Some(Deck::new(vec![
+            Card::Trump(Trump::_2),
+            Card::normal(Suit::Spade, SuitValue::_3)
+        ]))
+    );
+
+    let mut owned = Deck::new(vec![Card::normal(Suit::Club, SuitValue::_4)]);
+    let mut discard = Deck::new(vec![Card::normal(Suit::Heart, SuitValue::Jack)]);
+    assert_eq!(
+        give_back(&mut owned, &mut discard),
+        Some(Card::normal(Suit::Club, SuitValue::_4))
+    );
+    Ok(())
+}
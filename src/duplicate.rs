@@ -0,0 +1,217 @@
+use array_init::try_array_init;
+use derive_new::new;
+use itertools::Itertools;
+use rand::{Rng, SeedableRng, rngs::StdRng};
+use std::fmt;
+
+use crate::deck::Deck;
+use crate::errors::TarotErrorKind;
+use crate::family::Family;
+use crate::game::Game;
+use crate::mode::Mode;
+use crate::options::Options;
+use crate::player::Player;
+use crate::strategy;
+
+/// A stored deal, replayed by every line-up with the same cards
+#[derive(new, Clone, Debug, Eq, PartialEq)]
+pub struct Board {
+    hands: Vec<Deck>,
+    dog: Deck,
+    // seeds the decisions of each replay, so a session can be replayed
+    seed: u64,
+}
+
+impl Board {
+    #[must_use]
+    pub fn hands(&self) -> &[Deck] {
+        &self.hands
+    }
+    #[must_use]
+    pub const fn dog(&self) -> &Deck {
+        &self.dog
+    }
+}
+
+/// Score of a player relative to everyone else who held the same cards
+#[derive(new, Clone, Debug, PartialEq)]
+pub struct Standing {
+    pub name: String,
+    pub relative: f64,
+    pub boards: usize,
+}
+
+impl fmt::Display for Standing {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{} : {:+.2} over {} boards",
+            self.name, self.relative, self.boards
+        )
+    }
+}
+
+// RULE: every player of a line-up holds every hand, so no one sits out
+#[must_use]
+pub fn supports(options: Options, mode: Mode) -> bool {
+    options.family == Family::French && mode != Mode::Six
+}
+
+pub fn launch(mode: Mode, options: Options, deals: u64, seed: u64) -> Result<(), TarotErrorKind> {
+    if !supports(options, mode) {
+        return Err(TarotErrorKind::InvalidPlayers(mode.players().to_string()));
+    }
+    if mode == Mode::Two {
+        Duplicate::<{ Mode::Two.players() }>::new(options, seed)?.start(deals)?;
+    } else if mode == Mode::Three {
+        Duplicate::<{ Mode::Three.players() }>::new(options, seed)?.start(deals)?;
+    } else if mode == Mode::Four {
+        Duplicate::<{ Mode::Four.players() }>::new(options, seed)?.start(deals)?;
+    } else if mode == Mode::Five {
+        Duplicate::<{ Mode::Five.players() }>::new(options, seed)?.start(deals)?;
+    }
+    Ok(())
+}
+
+pub struct Duplicate<const MODE: usize> {
+    mode: Mode,
+    options: Options,
+    names: [String; MODE],
+    boards: Vec<Board>,
+    // score of each replay of each board, by seat, with the player sitting there
+    replays: Vec<Vec<[(usize, i32); MODE]>>,
+    rng: StdRng,
+}
+
+impl<const MODE: usize> Duplicate<MODE> {
+    pub fn new(options: Options, seed: u64) -> Result<Self, TarotErrorKind> {
+        let mode: Mode = MODE.try_into()?;
+        let names = try_array_init(|i| -> Result<String, TarotErrorKind> {
+            Ok(mode.player_name(i)?.to_string())
+        })?;
+        Ok(Self {
+            mode,
+            options,
+            names,
+            boards: Vec::new(),
+            replays: Vec::new(),
+            rng: StdRng::seed_from_u64(seed),
+        })
+    }
+    #[must_use]
+    pub fn boards(&self) -> &[Board] {
+        &self.boards
+    }
+    pub fn start(&mut self, deals: u64) -> Result<(), TarotErrorKind> {
+        for left in (1..=deals).rev() {
+            if !self.options.quiet {
+                println!("Boards left : {left}");
+            }
+            let board = self.board()?;
+            let replays = self.replay(&board)?;
+            self.boards.push(board);
+            self.replays.push(replays);
+        }
+        if !self.options.quiet {
+            println!("DUPLICATE ENDED");
+            for standing in self.standings() {
+                println!("\t{standing}");
+            }
+        }
+        Ok(())
+    }
+    // RULE: a board with a petit sec would be cancelled at every table, it is dealt again
+    fn board(&mut self) -> Result<Board, TarotErrorKind> {
+        loop {
+            let deck = self.options.shuffle.apply(None, &mut self.rng);
+            let (hands, dog) = deck.deal(self.mode, &mut self.rng)?;
+            if !hands.iter().any(Deck::petit_sec) {
+                return Ok(Board::new(hands, dog, self.rng.random()));
+            }
+        }
+    }
+    // every seat permutation of the players plays the board
+    fn replay(&self, board: &Board) -> Result<Vec<[(usize, i32); MODE]>, TarotErrorKind> {
+        let mut replays = Vec::new();
+        let mut rng = StdRng::seed_from_u64(board.seed);
+        for line_up in (0..MODE).permutations(MODE) {
+            let players = try_array_init(|seat| -> Result<Player, TarotErrorKind> {
+                let index = *line_up.get(seat).ok_or(TarotErrorKind::NoPlayer(seat))?;
+                let name = self
+                    .names
+                    .get(index)
+                    .ok_or(TarotErrorKind::NoPlayer(index))?;
+                let strategy = strategy::from_options(self.options, name == "South");
+                Ok(Player::new(name.clone(), self.options, strategy))
+            })?;
+            let mut game = Game::<MODE>::with_players(self.options, rng.random(), players)?;
+            game.play_deal(&board.hands, board.dog.clone())?;
+            game.is_consistent()?;
+            replays.push(try_array_init(|seat| -> Result<_, TarotErrorKind> {
+                let index = *line_up.get(seat).ok_or(TarotErrorKind::NoPlayer(seat))?;
+                Ok((index, game.player(seat)?.score()))
+            })?);
+        }
+        Ok(replays)
+    }
+    // RULE: the par of a seat is the mean score of everyone who held its cards,
+    // each board adds the mean difference to the par of a player over its replays
+    #[must_use]
+    pub fn standings(&self) -> Vec<Standing> {
+        let mut relatives = [0.0; MODE];
+        for replays in &self.replays {
+            let mut pars = [0.0; MODE];
+            for replay in replays {
+                for (par, (_, score)) in pars.iter_mut().zip(replay) {
+                    *par += f64::from(*score);
+                }
+            }
+            let count = f64::from(u32::try_from(replays.len()).unwrap_or(u32::MAX));
+            for replay in replays {
+                for (par, (index, score)) in pars.iter().zip(replay) {
+                    if let Some(relative) = relatives.get_mut(*index) {
+                        *relative += (f64::from(*score) - par / count) / count;
+                    }
+                }
+            }
+        }
+        let mut standings: Vec<Standing> = self
+            .names
+            .iter()
+            .zip(relatives)
+            .map(|(name, relative)| Standing::new(name.clone(), relative, self.boards.len()))
+            .collect();
+        standings.sort_by(|a, b| b.relative.total_cmp(&a.relative));
+        standings
+    }
+}
+
+#[test]
+fn duplicate_tests() -> Result<(), TarotErrorKind> {
+    let options = Options {
+        random: true,
+        auto: true,
+        quiet: true,
+        test: true,
+        ..Options::default()
+    };
+    let mut duplicate = Duplicate::<{ Mode::Three.players() }>::new(options, 42)?;
+    duplicate.start(2)?;
+    assert_eq!(duplicate.boards().len(), 2);
+    for board in duplicate.boards() {
+        assert_eq!(board.hands().len(), Mode::Three.players());
+        assert_eq!(board.dog().len(), Mode::Three.dog_size());
+    }
+    let standings = duplicate.standings();
+    assert_eq!(standings.len(), Mode::Three.players());
+    assert!(standings.windows(2).all(|w| match w {
+        [a, b] => a.relative >= b.relative,
+        _ => false,
+    }));
+    let sum: f64 = standings.iter().map(|standing| standing.relative).sum();
+    assert!(sum.abs() < 1e-6);
+    assert!(standings.iter().any(|standing| standing.relative != 0.0));
+
+    assert!(launch(Mode::Six, options, 1, 42).is_err());
+    Ok(())
+}
@@ -0,0 +1,50 @@
+use serde::de::Error;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::card::Card;
+use crate::normal::Normal;
+use crate::suit::Suit;
+use crate::suit_value::SuitValue;
+use crate::traits::Code;
+use crate::trump::Trump;
+
+// cards and their parts are written with their stable code, as "KH", "10S", "T21" or "F"
+macro_rules! serde_by_code {
+    ($($kind:ty),*) => {
+        $(
+            impl Serialize for $kind {
+                fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                    serializer.serialize_str(&self.code())
+                }
+            }
+            impl<'de> Deserialize<'de> for $kind {
+                fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                    let code = String::deserialize(deserializer)?;
+                    Self::from_code(&code).map_err(|e| D::Error::custom(format!("{e} : {code}")))
+                }
+            }
+        )*
+    };
+}
+
+serde_by_code!(Card, Normal, Suit, SuitValue, Trump);
+
+#[test]
+fn encoding_tests() -> Result<(), serde_json::Error> {
+    use crate::deck::Deck;
+
+    let king = Card::normal(Suit::Heart, SuitValue::King);
+    assert_eq!(serde_json::to_string(&king)?, r#""KH""#);
+    assert_eq!(serde_json::to_string(&Card::Trump(Trump::Fool))?, r#""F""#);
+    assert_eq!(
+        serde_json::from_str::<Card>(r#""T7""#)?,
+        Card::Trump(Trump::_7)
+    );
+    assert!(serde_json::from_str::<Card>(r#""ZZ""#).is_err());
+
+    let deck = Deck::full();
+    let json = serde_json::to_string(&deck)?;
+    assert!(json.starts_with(r#"[""#));
+    assert_eq!(serde_json::from_str::<Deck>(&json)?, deck);
+    Ok(())
+}
@@ -21,6 +21,12 @@ pub enum TarotErrorKind {
     InvalidScores(String),
     #[error("Invalid number of oudlers : {0}")]
     InvalidOudlersCount(Deck),
+    #[error("Illegal choice : {0}")]
+    IllegalChoice(String),
+    #[error("Invalid rules : {0}")]
+    InvalidRules(String),
+    #[error("Serialization error : {0}")]
+    Serialization(String),
     #[error("Invalid color")]
     InvalidColor,
     #[error("Random number distribution error")]
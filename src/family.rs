@@ -0,0 +1,34 @@
+use strum::{Display, EnumString};
+
+use crate::mode::Mode;
+
+/// Card games of the tarot family, the French rules or the Austrian Königrufen with 54 cards
+#[derive(Default, Display, EnumString, Debug, Copy, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[strum(serialize_all = "lowercase")]
+pub enum Family {
+    #[default]
+    French,
+    Koenigrufen,
+}
+
+impl Family {
+    // RULE: Königrufen is played by four
+    #[must_use]
+    pub const fn supports(self, mode: Mode) -> bool {
+        match self {
+            Self::French => true,
+            Self::Koenigrufen => matches!(mode, Mode::Four),
+        }
+    }
+}
+
+#[test]
+fn family_tests() {
+    use std::str::FromStr;
+
+    assert_eq!(Family::from_str("koenigrufen"), Ok(Family::Koenigrufen));
+    assert!(Family::French.supports(Mode::Two));
+    assert!(Family::Koenigrufen.supports(Mode::Four));
+    assert!(!Family::Koenigrufen.supports(Mode::Five));
+}
@@ -1,69 +1,150 @@
 use array_init::try_array_init;
-use ordered_float::OrderedFloat;
+use rand::SeedableRng;
+use rand::rngs::StdRng;
 use std::fmt;
 
+use crate::auction::Redeal;
+use crate::constants::MAX_CARDS;
 use crate::deck::Deck;
 use crate::errors::TarotErrorKind;
+use crate::family::Family;
 use crate::game_distributed::GameDistributed;
+use crate::koenigrufen;
 use crate::mode::Mode;
 use crate::options::Options;
 use crate::player::Player;
 use crate::player_in_game::PlayerInGame;
+use crate::record::DealRecord;
+use crate::strategy::{self, PlayerStrategy};
 
-#[derive(Debug, Clone)]
+#[derive(Debug)]
 pub struct Game<const MODE: usize> {
     options: Options,
     mode: Mode,
     players: [Player; MODE],
     dealer: usize,
+    deck: Option<Deck>,
+    records: Vec<DealRecord>,
+    seed: u64,
+    rng: StdRng,
+    // the dealer of a six players table, who sits out of the deal
+    sitting_out: Option<Player>,
 }
 
-pub fn launch(mode: Mode, options: Options, deals: u64) -> Result<(), TarotErrorKind> {
-    if mode == Mode::Three {
-        Game::<{ Mode::Three.players() }>::new(options)?.start(deals)?;
+/// What became of a deal
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum DealOutcome {
+    Played,
+    Passed,
+    // RULE: a petit sec cancels the deal
+    Cancelled,
+}
+
+pub fn launch(mode: Mode, options: Options, deals: u64, seed: u64) -> Result<(), TarotErrorKind> {
+    if options.family == Family::Koenigrufen {
+        return koenigrufen::launch(mode, options, deals, seed);
+    }
+    if mode == Mode::Two {
+        Game::<{ Mode::Two.players() }>::new(options, seed)?.start(deals)?;
+        return Ok(());
+    } else if mode == Mode::Three {
+        Game::<{ Mode::Three.players() }>::new(options, seed)?.start(deals)?;
         return Ok(());
     } else if mode == Mode::Four {
-        Game::<{ Mode::Four.players() }>::new(options)?.start(deals)?;
+        Game::<{ Mode::Four.players() }>::new(options, seed)?.start(deals)?;
         return Ok(());
     } else if mode == Mode::Five {
-        Game::<{ Mode::Five.players() }>::new(options)?.start(deals)?;
+        Game::<{ Mode::Five.players() }>::new(options, seed)?.start(deals)?;
+        return Ok(());
+    } else if mode == Mode::Six {
+        Game::<{ Mode::Six.seated().players() }>::six(options, seed)?.start(deals)?;
         return Ok(());
     }
     Ok(())
 }
 
+// the deck and the rng are left out, the seed replays the game
+#[cfg(feature = "serde")]
+impl<const MODE: usize> serde::Serialize for Game<MODE> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+
+        let mut game = serializer.serialize_struct("Game", 7)?;
+        game.serialize_field("options", &self.options)?;
+        game.serialize_field("mode", &self.mode)?;
+        game.serialize_field("players", self.players.as_slice())?;
+        game.serialize_field("sitting_out", &self.sitting_out)?;
+        game.serialize_field("dealer", &self.dealer)?;
+        game.serialize_field("records", &self.records)?;
+        game.serialize_field("seed", &self.seed)?;
+        game.end()
+    }
+}
+
 impl<const MODE: usize> fmt::Display for Game<MODE> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "Seed : {}", self.seed)?;
         writeln!(f, "Players : ")?;
         for player in &self.players {
             writeln!(f, "\t{player}")?;
         }
+        if let Some(player) = &self.sitting_out {
+            writeln!(f, "\t{player}, sitting out")?;
+        }
         Ok(())
     }
 }
 
 impl<const MODE: usize> Game<MODE> {
-    pub fn new(options: Options) -> Result<Self, TarotErrorKind> {
+    pub fn new(options: Options, seed: u64) -> Result<Self, TarotErrorKind> {
         let mode: Mode = MODE.try_into()?;
+        let strategies = try_array_init(|i| -> Result<_, TarotErrorKind> {
+            Ok(strategy::from_options(
+                options,
+                mode.player_name(options.direction.seat(i, MODE))? == "South",
+            ))
+        })?;
+        Self::with_strategies(options, seed, strategies)
+    }
+    pub fn with_strategies(
+        options: Options,
+        seed: u64,
+        strategies: [Box<dyn PlayerStrategy>; MODE],
+    ) -> Result<Self, TarotErrorKind> {
+        let mode: Mode = MODE.try_into()?;
+        let mut strategies = strategies.into_iter();
         let players: [Player; MODE] = try_array_init(|i| -> Result<Player, TarotErrorKind> {
-            let name = mode.player_name(i)?;
-            let random = options.test || name != "South";
-            let player_options = Options { random, ..options };
-            Ok(Player::new(name.to_string(), mode, player_options))
+            let name = mode.player_name(options.direction.seat(i, MODE))?;
+            let strategy = strategies.next().ok_or(TarotErrorKind::NoPlayer(i))?;
+            Ok(Player::new(name.to_string(), options, strategy))
         })?;
+        Self::with_players(options, seed, players)
+    }
+    pub fn with_players(
+        options: Options,
+        seed: u64,
+        players: [Player; MODE],
+    ) -> Result<Self, TarotErrorKind> {
         Ok(Self {
             players,
-            mode,
+            mode: MODE.try_into()?,
             options,
             dealer: 0,
+            deck: None,
+            records: Vec::new(),
+            seed,
+            rng: StdRng::seed_from_u64(seed),
+            sitting_out: None,
         })
     }
     pub fn is_consistent(&self) -> Result<(), TarotErrorKind> {
-        let mut sum = OrderedFloat(0.0);
-        for player in &self.players {
-            sum += player.score();
-        }
-        if sum != 0.0 {
+        let sum: i32 = self
+            .players
+            .iter()
+            .chain(&self.sitting_out)
+            .map(Player::score)
+            .sum();
+        if sum != 0 {
             eprintln!("Inconsistent points sum : {sum}");
             return Err(TarotErrorKind::InvalidScores(sum.to_string()));
         }
@@ -87,21 +168,31 @@ impl<const MODE: usize> Game<MODE> {
     pub const fn players(&self) -> &[Player; MODE] {
         &self.players
     }
-    pub fn start(mut self, mut deals: u64) -> Result<(), TarotErrorKind> {
+    #[must_use]
+    pub const fn sitting_out(&self) -> Option<&Player> {
+        self.sitting_out.as_ref()
+    }
+    pub const fn players_and_rng(&mut self) -> (&[Player; MODE], &mut StdRng) {
+        (&self.players, &mut self.rng)
+    }
+    pub fn start(&mut self, mut deals: u64) -> Result<(), TarotErrorKind> {
         while deals > 0 {
             if !self.options.quiet {
                 println!("Deals left : {deals}");
             }
-            if let Ok(Some(mut game_distributed)) = self.distribute() {
-                if let Some(mut game_started) = game_distributed.bidding_and_discard()? {
-                    while !game_started.finished() {
-                        game_started.play()?;
+            let new_deck = self.options.shuffle.apply(self.deck.take(), &mut self.rng);
+            let (hands, dog) = new_deck.deal(self.mode, &mut self.rng)?;
+            match self.play_deal(&hands, dog)? {
+                DealOutcome::Played => deals -= 1,
+                DealOutcome::Passed => {
+                    if !self.options.quiet {
+                        println!("Everyone passed !");
+                    }
+                    if self.options.redeal == Redeal::SameDealer {
+                        continue;
                     }
-                    game_started.count_points()?;
-                    deals -= 1;
-                } else if !self.options.quiet {
-                    println!("Everyone passed !");
                 }
+                DealOutcome::Cancelled => {}
             }
             self.rotate_dealer();
         }
@@ -111,18 +202,37 @@ impl<const MODE: usize> Game<MODE> {
         }
         Ok(())
     }
-    fn distribute(&'_ mut self) -> Result<Option<GameDistributed<'_, MODE>>, TarotErrorKind> {
+    // plays a whole deal of these hands, given seat by seat
+    pub fn play_deal(&mut self, hands: &[Deck], dog: Deck) -> Result<DealOutcome, TarotErrorKind> {
+        let Some(mut game_distributed) = self.distribute(hands, dog)? else {
+            return Ok(DealOutcome::Cancelled);
+        };
+        let Some(mut game_started) = game_distributed.bidding_and_discard()? else {
+            return Ok(DealOutcome::Passed);
+        };
+        while !game_started.finished() {
+            #[cfg(feature = "serde")]
+            game_started.show_snapshot()?;
+            game_started.play()?;
+        }
+        #[cfg(feature = "serde")]
+        game_started.show_snapshot()?;
+        game_started.count_points()?;
+        Ok(DealOutcome::Played)
+    }
+    fn distribute(
+        &'_ mut self,
+        hands: &[Deck],
+        mut dog: Deck,
+    ) -> Result<Option<GameDistributed<'_, MODE>>, TarotErrorKind> {
         let mut players_in_game: [PlayerInGame; MODE] =
             try_array_init(|i| -> Result<PlayerInGame, TarotErrorKind> {
-                Ok(PlayerInGame::new(self.mode, *self.player(i)?.options()))
+                Ok(PlayerInGame::new(self.mode, *self.player(i)?.options(), i))
             })?;
 
-        let mut new_deck = Deck::random();
-        let mut dog = new_deck.give(self.mode.dog_size());
         dog.sort();
-        for player in &mut players_in_game {
-            let buffer = new_deck.give(self.mode.cards_per_player());
-            player.extend_hand(&buffer);
+        for (player, hand) in players_in_game.iter_mut().zip(hands) {
+            player.deal(hand)?;
         }
 
         for player in &players_in_game {
@@ -130,6 +240,9 @@ impl<const MODE: usize> Game<MODE> {
                 if !self.options.quiet {
                     dbg!("Petit sec, cancel the game");
                 }
+                let mut cards: Vec<Deck> = players_in_game.iter().map(PlayerInGame::held).collect();
+                cards.push(dog);
+                self.gather(&cards)?;
                 return Ok(None);
             }
         }
@@ -140,22 +253,78 @@ impl<const MODE: usize> Game<MODE> {
             players_in_game,
         )))
     }
-    pub fn rotate_at(&mut self, index: usize) {
+    pub fn record(&mut self, record: DealRecord) {
+        self.records.push(record);
+    }
+    #[must_use]
+    pub fn records(&self) -> &[DealRecord] {
+        &self.records
+    }
+    // piles put on top of each other make the deck of the next deal
+    pub fn gather(&mut self, piles: &[Deck]) -> Result<(), TarotErrorKind> {
+        let mut deck = Deck::default();
+        for pile in piles {
+            deck.extend(pile);
+        }
+        // a lost card would silently restart the next deal from a new pack
+        if deck.len() != MAX_CARDS {
+            return Err(TarotErrorKind::InvalidDeck(deck));
+        }
+        self.deck = Some(deck);
+        Ok(())
+    }
+    // RULE: players are rotated after each deal, so the dealer always sits last
+    #[must_use]
+    pub const fn dealer_seat(&self) -> usize {
+        MODE - 1
+    }
+    pub const fn rotate_at(&mut self, index: usize) {
         self.players.rotate_left(index);
     }
+    // RULE: the next dealer is the player after the dealer, who is served and plays first
     fn rotate_dealer(&mut self) {
-        if self.dealer == self.players.len() - 1 {
-            self.dealer = 0;
-        } else {
-            self.dealer += 1;
+        let table = MODE + usize::from(self.sitting_out.is_some());
+        self.dealer = (self.dealer + 1) % table;
+        self.rotate_at(1);
+        // the new dealer leaves the table to the one who sat out
+        if let (Some(sitting_out), Some(dealer)) =
+            (self.sitting_out.as_mut(), self.players.last_mut())
+        {
+            std::mem::swap(sitting_out, dealer);
         }
-        self.rotate_at(self.dealer);
+    }
+}
+
+impl Game<{ Mode::Six.seated().players() }> {
+    // RULE: at six, the dealer sits out and the five others play
+    pub fn six(options: Options, seed: u64) -> Result<Self, TarotErrorKind> {
+        let table = Mode::Six.players();
+        let mut players = (0..table)
+            .map(|i| -> Result<Player, TarotErrorKind> {
+                let name = Mode::Six.player_name(options.direction.seat(i, table))?;
+                let strategy = strategy::from_options(options, name == "South");
+                Ok(Player::new(name.to_string(), options, strategy))
+            })
+            .collect::<Result<Vec<Player>, TarotErrorKind>>()?;
+        let sitting_out = players.pop();
+        let players = players
+            .try_into()
+            .map_err(|_| TarotErrorKind::InvalidPlayers(table.to_string()))?;
+        let mut game = Self::with_players(options, seed, players)?;
+        game.sitting_out = sitting_out;
+        Ok(game)
     }
 }
 
 #[test]
-fn game_tests() {
+fn game_tests() -> Result<(), TarotErrorKind> {
+    use crate::direction::Direction;
+    use crate::ismcts::Budget;
     use crate::mode::Mode;
+    use crate::points::Points;
+    use crate::rules::Preset;
+    use crate::shuffle::Shuffle;
+    use crate::strategy::Bot;
     use strum::IntoEnumIterator;
     let options = Options {
         random: true,
@@ -164,8 +333,84 @@ fn game_tests() {
         quiet: true,
         no_slam: false,
         attack: false,
+        bot: Bot::Random,
+        budget: Budget::default(),
+        direction: Direction::Clockwise,
+        shuffle: Shuffle::Riffle,
+        redeal: Redeal::SameDealer,
+        rules: Preset::House.into(),
+        family: Family::French,
+        json: false,
     };
     for mode in Mode::iter() {
-        assert_eq!(launch(mode, options, 1), Ok(()));
+        assert_eq!(launch(mode, options, 1, 0), Ok(()));
     }
+
+    let mut first = Game::<{ Mode::Five.players() }>::new(options, 42)?;
+    first.start(5)?;
+    let mut second = Game::<{ Mode::Five.players() }>::new(options, 42)?;
+    second.start(5)?;
+    for (first_player, second_player) in first.players().iter().zip(second.players()) {
+        assert_eq!(first_player.score(), second_player.score());
+    }
+    assert_eq!(first.records(), second.records());
+    assert!(first.records().len() <= 5);
+
+    // a pack missing a card is never kept for the next deal
+    let mut short = Deck::full();
+    assert_eq!(short.give(1).len(), 1);
+    assert!(first.gather(&[short]).is_err());
+    assert!(first.gather(&[Deck::full()]).is_ok());
+
+    let mut six = Game::<{ Mode::Six.seated().players() }>::six(options, 42)?;
+    let benched = six.sitting_out().map(|player| player.name().to_string());
+    let benched_score = six.sitting_out().map(Player::score);
+    six.start(1)?;
+    six.is_consistent()?;
+    // the player who sat out scores nothing for the deal
+    assert_eq!(
+        six.players()
+            .iter()
+            .find(|player| Some(player.name().to_string()) == benched)
+            .map(Player::score),
+        benched_score
+    );
+    assert!(
+        six.players()
+            .iter()
+            .any(|player| Some(player.name().to_string()) == benched)
+    );
+    assert_ne!(six.sitting_out().map(Player::name), benched.as_deref());
+
+    // the taker keeps his seat whoever leads each turn
+    let options = Options {
+        no_slam: true,
+        ..options
+    };
+    for seed in 0..10 {
+        let mut game = Game::<{ Mode::Four.players() }>::new(options, seed)?;
+        let deck = options.shuffle.apply(None, &mut game.rng);
+        let (hands, dog) = deck.deal(game.mode, &mut game.rng)?;
+        let Some(mut game_distributed) = game.distribute(&hands, dog)? else {
+            continue;
+        };
+        let Some(mut game_started) = game_distributed.bidding_and_discard()? else {
+            continue;
+        };
+        let taker_index = game_started.taker_index();
+        let taker = game_started.player(taker_index)?.name().to_string();
+        while !game_started.finished() {
+            game_started.play()?;
+        }
+        let (player, player_in_game) = game_started.player_and_his_game(taker_index)?;
+        assert_eq!(player.name(), taker);
+        assert!(player_in_game.is_taker());
+
+        // a failed contract costs the taker
+        let won = player_in_game.points() >= player_in_game.points_for_oudlers()?;
+        game_started.count_points()?;
+        let score = game_started.player(taker_index)?.score();
+        assert_eq!(won, score > 0);
+    }
+    Ok(())
 }
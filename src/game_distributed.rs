@@ -1,8 +1,10 @@
+use crate::auction::Auction;
 use crate::contract::Contract;
 use crate::deck::Deck;
 use crate::errors::TarotErrorKind;
 use crate::game::Game;
 use crate::game_started::GameStarted;
+use crate::misere::ShownMisere;
 use crate::options::Options;
 use crate::player::Player;
 use crate::player_in_game::PlayerInGame;
@@ -10,8 +12,8 @@ use crate::role::Role;
 use crate::team::Team;
 use derive_new::new;
 use itertools::{Either, Itertools};
+use rand::rngs::StdRng;
 use std::fmt;
-use strum::IntoEnumIterator;
 
 #[derive(new)]
 pub struct GameDistributed<'a, const MODE: usize> {
@@ -19,6 +21,8 @@ pub struct GameDistributed<'a, const MODE: usize> {
     options: Options,
     dog: Deck,
     players_in_game: [PlayerInGame; MODE],
+    #[new(default)]
+    miseres: Vec<ShownMisere>,
 }
 
 impl<'a, const MODE: usize> GameDistributed<'a, MODE> {
@@ -47,6 +51,18 @@ impl<'a, const MODE: usize> GameDistributed<'a, MODE> {
         };
         Ok((player, player_in_game))
     }
+    pub fn player_and_his_game_with_rng(
+        &mut self,
+        index: usize,
+    ) -> Result<(&Player, &mut PlayerInGame, &mut StdRng), TarotErrorKind> {
+        let (players, rng) = self.game.players_and_rng();
+        let (Some(player), Some(player_in_game)) =
+            (players.get(index), self.players_in_game.get_mut(index))
+        else {
+            return Err(TarotErrorKind::NoPlayer(index));
+        };
+        Ok((player, player_in_game, rng))
+    }
     pub fn player_and_his_game_mut(
         &mut self,
         index: usize,
@@ -58,72 +74,103 @@ impl<'a, const MODE: usize> GameDistributed<'a, MODE> {
         };
         Ok((player, player_in_game))
     }
+    #[must_use]
+    pub const fn dog(&self) -> &Deck {
+        &self.dog
+    }
+    #[must_use]
+    pub const fn players_in_game(&self) -> &[PlayerInGame; MODE] {
+        &self.players_in_game
+    }
+    #[must_use]
+    pub fn miseres(&self) -> &[ShownMisere] {
+        &self.miseres
+    }
+    // RULE: misères are announced in the order of the auction, before the first bid
+    fn announce_miseres(&mut self) -> Result<(), TarotErrorKind> {
+        let first = (self.game.dealer_seat() + 1) % MODE;
+        let (players, rng) = self.game.players_and_rng();
+        for offset in 0..MODE {
+            let seat = (first + offset) % MODE;
+            let (Some(player), Some(player_in_game)) =
+                (players.get(seat), self.players_in_game.get(seat))
+            else {
+                return Err(TarotErrorKind::NoPlayer(seat));
+            };
+            for shown in player_in_game.announce_miseres(player, rng)? {
+                if !self.options.quiet {
+                    println!("Player {} announces {shown}", player.name());
+                }
+                for player_in_game in &mut self.players_in_game {
+                    player_in_game.knowledge_mut().show_misere(&shown);
+                }
+                self.miseres.push(shown);
+            }
+        }
+        Ok(())
+    }
     pub fn finished(&self) -> bool {
         self.players_in_game.iter().all(PlayerInGame::last_turn)
     }
-    pub fn rotate_at(&mut self, index: usize) {
-        self.players_in_game.rotate_left(index);
-        self.game.rotate_at(index);
-    }
     pub fn bidding_and_discard(
         &'a mut self,
     ) -> Result<Option<GameStarted<'a, MODE>>, TarotErrorKind> {
         let quiet = self.options.quiet;
-        let mut contracts: Vec<Contract> = Contract::iter().collect();
-        let mut slam_index: Option<usize> = None;
-        let mut taker_index: Option<usize> = None;
-        let mut contract: Option<Contract> = None;
+        self.announce_miseres()?;
+        let mut auction = Auction::new(MODE, self.game.dealer_seat());
 
-        for (current_player_index, current_player_in_game) in
-            self.players_in_game.iter_mut().enumerate()
-        {
-            let current_player = self.game.player(current_player_index)?;
-            let player_contract =
-                current_player_in_game.choose_contract_among(current_player, &contracts)?;
-            match (contract, player_contract) {
-                (None | Some(_), None) => {}
-                (None | Some(_), Some(player_contract)) => {
-                    taker_index = Some(current_player_index);
-                    if !self.options.quiet {
-                        println!(
-                            "Player {} has chosen contract {player_contract}",
-                            current_player.name()
-                        );
-                    }
-                    contracts.retain(|other_contract| {
-                        other_contract.multiplier() > player_contract.multiplier()
-                    });
-                    if current_player_in_game.announce_slam()? {
-                        if !self.options.quiet {
-                            println!("Player {current_player} announced a slam");
-                        }
-                        slam_index = Some(current_player_index);
-                    }
-                    contract = Some(player_contract);
+        let (players, rng) = self.game.players_and_rng();
+        while let Some(current_player_index) = auction.current() {
+            let current_player = players
+                .get(current_player_index)
+                .ok_or(TarotErrorKind::NoPlayer(current_player_index))?;
+            let current_player_in_game = self
+                .players_in_game
+                .get(current_player_index)
+                .ok_or(TarotErrorKind::NoPlayer(current_player_index))?;
+            let player_contract = current_player_in_game.choose_contract_among(
+                current_player,
+                &auction.available(),
+                rng,
+            )?;
+            auction.bid(current_player_index, player_contract)?;
+            if !quiet {
+                match player_contract {
+                    Some(player_contract) => println!(
+                        "Player {} has chosen contract {player_contract}",
+                        current_player.name()
+                    ),
+                    None => println!("Player {} passes", current_player.name()),
                 }
             }
+            for player_in_game in &mut self.players_in_game {
+                player_in_game.knowledge_mut().set_auction(&auction);
+            }
         }
-        let Some(contract) = contract else {
-            return Ok(None);
-        };
-        let Some(taker_index) = taker_index else {
+        let Some((taker_index, contract)) = auction.taker() else {
+            // RULE: when everyone passes, the hands are gathered for the next deal
+            let mut cards: Vec<Deck> = self
+                .players_in_game
+                .iter()
+                .map(PlayerInGame::held)
+                .collect();
+            cards.push(self.dog.clone());
+            self.game.gather(&cards)?;
             return Ok(None);
         };
 
-        // RULE: player who slammed must start
-        if let Some(slammer) = slam_index {
-            if !self.options.quiet {
-                println!("Chelem announced so {slammer} must start.");
-            }
-            self.rotate_at(slammer);
-        }
-
         let Some(taker) = self.players_in_game.get(taker_index) else {
             return Err(TarotErrorKind::NoTaker(taker_index));
         };
-        let callee = taker.call()?;
+        let (players, rng) = self.game.players_and_rng();
+        let taker_player = players
+            .get(taker_index)
+            .ok_or(TarotErrorKind::NoTaker(taker_index))?;
+        let callee = taker.call(taker_player, rng)?;
         for (current_player_index, current_player) in self.players_in_game.iter_mut().enumerate() {
             current_player.set_callee(callee);
+            current_player.set_taker_index(taker_index);
+            current_player.knowledge_mut().set_contract(contract);
             current_player.set_team(Team::Defense);
             current_player.set_role(Role::Defenser);
             if current_player_index == taker_index {
@@ -160,10 +207,10 @@ impl<'a, const MODE: usize> GameDistributed<'a, MODE> {
                 continue;
             }
 
-            let taker = self
-                .game
-                .player(attacker_index)
-                .map_err(|_| TarotErrorKind::NoTaker(attacker_index))?;
+            let (players, rng) = self.game.players_and_rng();
+            let taker = players
+                .get(attacker_index)
+                .ok_or(TarotErrorKind::NoTaker(attacker_index))?;
 
             match contract {
                 Contract::GardeSans => {
@@ -190,11 +237,42 @@ impl<'a, const MODE: usize> GameDistributed<'a, MODE> {
                         println!("Taker {} received the dog", taker.name());
                     }
                     attacker_in_game.extend_hand(&self.dog);
-                    attacker_in_game.discard()?;
+                    attacker_in_game.discard(taker, rng)?;
+                }
+            }
+        }
+        // RULE: the dog is shown to everyone in a petite or a garde
+        if matches!(contract, Contract::Petite | Contract::Garde) {
+            // RULE: with the called card in the dog, the taker plays alone
+            let alone = callee.is_some_and(|callee| self.dog.has(&callee));
+            if alone && !quiet {
+                println!("Called card is in the dog, the taker plays alone");
+            }
+            for player_in_game in &mut self.players_in_game {
+                player_in_game.knowledge_mut().show_dog(&self.dog);
+                if alone {
+                    player_in_game.knowledge_mut().reveal_ally(taker_index);
                 }
             }
         }
-        let game_started = GameStarted::new(self, taker_index, contract, self.options);
+        // RULE: a slam is announced once the discard is done, and the slammer must start
+        let (players, rng) = self.game.players_and_rng();
+        let taker = players
+            .get(taker_index)
+            .ok_or(TarotErrorKind::NoTaker(taker_index))?;
+        let taker_in_game = self
+            .players_in_game
+            .get_mut(taker_index)
+            .ok_or(TarotErrorKind::NoTaker(taker_index))?;
+        let leader = if taker_in_game.announce_slam(taker, rng)? {
+            if !quiet {
+                println!("Player {taker} announced a slam and must start");
+            }
+            taker_index
+        } else {
+            0
+        };
+        let game_started = GameStarted::new(self, taker_index, contract, self.options, leader);
         Ok(Some(game_started))
     }
 }
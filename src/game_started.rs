@@ -1,17 +1,21 @@
 use derive_new::new;
-use ordered_float::OrderedFloat;
 use std::fmt;
 
-use crate::constants::{BASE_CONTRACT_POINTS, MAX_CARDS};
 use crate::contract::Contract;
+use crate::deck::Deck;
 use crate::errors::TarotErrorKind;
 use crate::game_distributed::GameDistributed;
+use crate::handle::ShownHandle;
 use crate::mode::Mode;
 use crate::options::Options;
 use crate::player::Player;
 use crate::player_in_game::PlayerInGame;
-use crate::points::Points;
+use crate::points::{HalfPoints, Points};
+use crate::record::DealRecord;
 use crate::role::Role;
+use crate::slam::SlamOutcome;
+#[cfg(feature = "serde")]
+use crate::snapshot::{DealSnapshot, SeatSnapshot};
 use crate::team::Team;
 use crate::turn::Turn;
 
@@ -21,6 +25,7 @@ pub struct GameStarted<'a, const MODE: usize> {
     taker_index: usize,
     contract: Contract,
     options: Options,
+    leader: usize,
 
     #[new(default)]
     petit_au_bout_for_team: Option<Team>,
@@ -28,6 +33,8 @@ pub struct GameStarted<'a, const MODE: usize> {
     defense_cards: usize,
     #[new(default)]
     attack_cards: usize,
+    #[new(default)]
+    handles: Vec<ShownHandle>,
 }
 
 impl<const MODE: usize> fmt::Display for GameStarted<'_, MODE> {
@@ -53,10 +60,81 @@ impl<const MODE: usize> GameStarted<'_, MODE> {
     pub fn is_consistent(&mut self) -> Result<(), TarotErrorKind> {
         self.game_distributed.game().is_consistent()
     }
+    // RULE: tricks are gathered team by team, the ally gave its cards to the taker
+    fn gather(&mut self) -> Result<(), TarotErrorKind> {
+        let taker_index = self.taker_index;
+        let (_, players_in_game) = self.players_and_their_game_mut();
+        let mut piles: Vec<Deck> = players_in_game
+            .iter()
+            .filter(|player_in_game| !player_in_game.is_attack())
+            .map(PlayerInGame::all_cards)
+            .collect();
+        let taker = players_in_game
+            .get(taker_index)
+            .ok_or(TarotErrorKind::NoTaker(taker_index))?;
+        piles.push(taker.all_cards());
+        self.game_distributed.game().gather(&piles)
+    }
+    // the other team won no card so far, the fool given back to its owner does not count
+    const fn won_every_turn(&self, team: Team) -> bool {
+        match team {
+            Team::Attack => self.defense_cards == 0,
+            Team::Defense => self.attack_cards == 0,
+        }
+    }
+    #[cfg(feature = "serde")]
+    pub fn snapshot(&self) -> Result<DealSnapshot, TarotErrorKind> {
+        let players_in_game = self.game_distributed.players_in_game();
+        let taker = players_in_game
+            .get(self.taker_index)
+            .ok_or(TarotErrorKind::NoTaker(self.taker_index))?;
+        let seats = players_in_game
+            .iter()
+            .enumerate()
+            .map(
+                |(index, player_in_game)| -> Result<SeatSnapshot, TarotErrorKind> {
+                    let player = self.player(index)?;
+                    Ok(SeatSnapshot {
+                        name: player.name().to_string(),
+                        score: player.score(),
+                        role: *player_in_game.role(),
+                        team: *player_in_game.team(),
+                        hand: player_in_game.hand().clone(),
+                        hidden: player_in_game.table().hidden(),
+                        owned: player_in_game.owned().clone(),
+                        discard: player_in_game.discarded().clone(),
+                    })
+                },
+            )
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(DealSnapshot {
+            mode: *taker.mode(),
+            contract: self.contract,
+            taker: self.taker_index,
+            callee: taker.callee(),
+            leader: self.leader,
+            dog: self.game_distributed.dog().clone(),
+            handles: self.handles.clone(),
+            tricks: taker.knowledge().turns().to_vec(),
+            seats,
+        })
+    }
+    // one JSON line for other tools before each trick, and once the deal is over
+    #[cfg(feature = "serde")]
+    pub fn show_snapshot(&self) -> Result<(), TarotErrorKind> {
+        if self.options.json {
+            println!("{}", self.snapshot()?.to_json()?);
+        }
+        Ok(())
+    }
     #[must_use]
     pub fn finished(&self) -> bool {
         self.game_distributed.finished()
     }
+    #[must_use]
+    pub const fn taker_index(&self) -> usize {
+        self.taker_index
+    }
     pub const fn mode(&mut self) -> &Mode {
         self.game_distributed.game().mode()
     }
@@ -87,9 +165,11 @@ impl<const MODE: usize> GameStarted<'_, MODE> {
         let mut turn = Turn::default();
         let mut master_player_index: usize = 0;
         let quiet = self.options.quiet;
-        for current_player_index in 0..MODE {
-            let (current_player, current_player_in_game) =
-                self.player_and_his_game_mut(current_player_index)?;
+        for offset in 0..MODE {
+            let current_player_index = (self.leader + offset) % MODE;
+            let (current_player, current_player_in_game, rng) = self
+                .game_distributed
+                .player_and_his_game_with_rng(current_player_index)?;
             let current_player_name = current_player.name();
 
             if !quiet {
@@ -101,34 +181,49 @@ impl<const MODE: usize> GameStarted<'_, MODE> {
                 ));
             };
 
-            let card = current_player_in_game.play_card(current_player, &turn)?;
+            let card = current_player_in_game.play_card(current_player, &turn, rng)?;
+            let shown_handle = current_player_in_game.take_shown_handle();
+            if let Some(shown) = &shown_handle
+                && !quiet
+            {
+                println!(
+                    "Player {current_player_name} shows a {} handle : {}",
+                    shown.handle(),
+                    shown.cards()
+                );
+            }
+            // RULE: the ally is known when the called card is played
+            let revealed = (current_player_in_game.callee() == Some(card)).then(|| {
+                if current_player_in_game.is_taker() {
+                    format!("Called card {card} is played by the taker, who plays alone")
+                } else {
+                    format!("Called card {card} is played, {current_player_name} is the ally")
+                }
+            });
             if card.is_fool() {
                 if current_player_in_game.last_turn() {
                     // RULE: exception in the last turn, the fool is in game and can be lost
-                    turn.put(card);
-                    match team {
-                        Team::Attack => {
-                            if self.attack_cards == MAX_CARDS - self.mode().dog_size() {
-                                turn.master_index = Some(turn.len() - 1);
-                                master_player_index = current_player_index;
-                            }
-                        }
-                        Team::Defense => {
-                            if self.defense_cards == MAX_CARDS - self.mode().dog_size() {
-                                turn.master_index = Some(turn.len() - 1);
-                                master_player_index = current_player_index;
-                            }
-                        }
+                    turn.put(current_player_index, card);
+                    // RULE: the fool of a team which won every other turn wins the last one
+                    if self.won_every_turn(team) {
+                        turn.fool_wins();
+                        master_player_index = current_player_index;
                     }
                 } else {
                     // RULE: the fool is always preserved to his owner
                     current_player_in_game.push_owned(card);
-                    turn.put(card);
+                    turn.put(current_player_index, card);
                 }
             } else {
-                turn.put(card);
-                if let Some(master) = turn.master_card() {
-                    if master.master(card) {
+                let previous_master = turn.master_card().copied();
+                turn.put(current_player_index, card);
+                match previous_master {
+                    _ if turn.is_won_by_fool() => {
+                        if !quiet {
+                            println!("The fool wins the last turn, whatever {card} is");
+                        }
+                    }
+                    Some(master) if turn.master_player() != Some(current_player_index) => {
                         if !quiet {
                             let master_player =
                                 self.game_distributed
@@ -139,41 +234,59 @@ impl<const MODE: usize> GameStarted<'_, MODE> {
                                 master_player.name()
                             );
                         }
-                    } else {
+                    }
+                    Some(_) => {
                         if !quiet {
                             println!(
                                 "Master card is {card}, so player {current_player_name} becomes master",
                             );
                         }
                         master_player_index = current_player_index;
-                        turn.master_index = Some(turn.len() - 1);
                     }
-                } else {
-                    if !quiet {
-                        println!(
-                            "First card is {card}, so player {current_player_name} becomes master",
-                        );
+                    None => {
+                        if !quiet {
+                            println!(
+                                "First card is {card}, so player {current_player_name} becomes master",
+                            );
+                        }
+                        master_player_index = current_player_index;
                     }
-                    master_player_index = current_player_index;
-                    turn.master_index = Some(turn.len() - 1);
                 }
             }
             if !quiet {
                 println!("{turn}");
             }
+            if let Some(reveal) = &revealed
+                && !quiet
+            {
+                println!("{reveal}");
+            }
+            let (_, players_in_game) = self.players_and_their_game_mut();
+            for player_in_game in players_in_game {
+                if revealed.is_some() {
+                    player_in_game
+                        .knowledge_mut()
+                        .reveal_ally(current_player_index);
+                }
+                if let Some(shown_handle) = &shown_handle {
+                    player_in_game.knowledge_mut().show_handle(shown_handle);
+                }
+                player_in_game.knowledge_mut().observe(&turn);
+            }
+            self.handles.extend(shown_handle);
         }
 
-        let mode = *self.mode();
-        let attack_near_slam = self.attack_cards == MAX_CARDS - mode.dog_size() - mode.players();
-        if attack_near_slam && !quiet {
-            println!("Attack is near slam!");
-        }
-        let defense_near_slam = self.defense_cards == MAX_CARDS - mode.dog_size() - mode.players();
-        if defense_near_slam && !quiet {
-            println!("Defense is near slam!");
-        }
+        let attack_on_slam = self.won_every_turn(Team::Attack);
+        let defense_on_slam = self.won_every_turn(Team::Defense);
 
         let (players, players_in_game) = self.players_and_their_game_mut();
+        for player_in_game in players_in_game.iter_mut() {
+            player_in_game.knowledge_mut().record_turn(&turn);
+        }
+        let fool_kept_by = players_in_game
+            .iter()
+            .find(|player_in_game| player_in_game.hand().has_fool())
+            .and_then(|player_in_game| *player_in_game.team());
         let Some(master) = players.get(master_player_index) else {
             return Err(TarotErrorKind::NoMaster(master_player_index));
         };
@@ -184,7 +297,6 @@ impl<const MODE: usize> GameStarted<'_, MODE> {
         let Some(master_player_in_game) = players_in_game.get_mut(master_player_index) else {
             return Err(TarotErrorKind::NoMaster(master_player_index));
         };
-        // RULE: petit au bout works for last turn, or before last turn if a slam is occuring
         let last_turn = master_player_in_game.last_turn();
         if last_turn && !quiet {
             println!("Last turn detected");
@@ -198,9 +310,19 @@ impl<const MODE: usize> GameStarted<'_, MODE> {
             return Err(TarotErrorKind::NoTeamForPlayer(master.name().to_string()));
         };
 
-        let turn_cards = turn.take_cards_except_fool();
+        let turn_cards = if last_turn {
+            turn.take_cards()
+        } else {
+            turn.take_cards_except_fool()
+        };
+        // RULE: petit au bout works for last turn, or before last turn when the team on slam keeps the fool for the last one
+        let on_slam = match master_player_team {
+            Team::Attack => attack_on_slam,
+            Team::Defense => defense_on_slam,
+        };
+        let slam_with_fool = on_slam && fool_kept_by == Some(*master_player_team);
         let petit_au_bout_for_team = if turn_cards.has_petit()
-            && (last_turn || (before_last_turn && (attack_near_slam || defense_near_slam)))
+            && (last_turn || (before_last_turn && slam_with_fool))
         {
             if !quiet {
                 println!(
@@ -221,8 +343,8 @@ impl<const MODE: usize> GameStarted<'_, MODE> {
             Team::Defense => defense_cards = turn_cards.len(),
         }
         master_player_in_game.extend_owned(&turn_cards);
-        self.game_distributed.rotate_at(master_player_index);
-        self.petit_au_bout_for_team = petit_au_bout_for_team;
+        self.leader = master_player_index;
+        self.petit_au_bout_for_team = petit_au_bout_for_team.or(self.petit_au_bout_for_team);
         self.attack_cards += attack_cards;
         self.defense_cards += defense_cards;
         Ok(())
@@ -236,7 +358,6 @@ impl<const MODE: usize> GameStarted<'_, MODE> {
         let mut defense: Vec<usize> = Vec::new();
         let mut owning_card_player_index: Option<usize> = None;
         let mut missing_card_player_index: Option<usize> = None;
-        let mut handle_bonuses = OrderedFloat(0.0);
         let quiet = self.options.quiet;
         for current_player_index in 0..MODE {
             let (current_player, current_player_in_game) =
@@ -254,13 +375,6 @@ impl<const MODE: usize> GameStarted<'_, MODE> {
             if current_player_in_game.missing_card() {
                 missing_card_player_index = Some(current_player_index);
             }
-            if let Some(handle) = &current_player_in_game.handle() {
-                let handle_bonus = handle.points();
-                handle_bonuses += handle_bonus;
-                if !quiet {
-                    println!("Handle bonus for role {role} : {handle_bonus}");
-                }
-            }
             match role {
                 Role::Taker => {
                     attack.push(current_player_index);
@@ -340,43 +454,43 @@ impl<const MODE: usize> GameStarted<'_, MODE> {
             taker_in_game.extend_owned(&ally_cards);
         }
 
+        let slam = SlamOutcome::new(
+            self.player_and_his_game(self.taker_index)?.1.slam(),
+            self.won_every_turn(Team::Attack),
+            self.won_every_turn(Team::Defense),
+        );
+        let rules = self.options.rules;
+        let slam_bonus = rules.slam_bonus(slam);
         let (taker, taker_in_game) = self.player_and_his_game_mut(self.taker_index)?;
-        let slam_bonus = taker_in_game.slam_bonus();
         let taker_points = taker_in_game.points();
         let points_for_oudlers = taker_in_game.points_for_oudlers()?;
 
         if !quiet {
-            println!("Taker {taker} slam bonus: {slam_bonus}");
+            println!("Taker {taker} {slam} bonus: {slam_bonus}");
             println!("Taker {taker} owned points: {taker_points}");
             println!("Contract todo: {points_for_oudlers}");
-            println!("Contract base: {BASE_CONTRACT_POINTS}");
-            let difference = taker_points - points_for_oudlers;
-            println!("Contract difference: {difference}");
+            println!("Contract base: {}", rules.base);
         }
 
-        let contract_points = if taker_points >= points_for_oudlers {
-            if !quiet {
-                let total = taker_points - points_for_oudlers + BASE_CONTRACT_POINTS;
-                println!("Contract total: {total}");
-            }
-            (taker_points - points_for_oudlers + BASE_CONTRACT_POINTS) * self.contract.multiplier()
-        } else {
-            if !quiet {
-                let total = taker_points - points_for_oudlers - BASE_CONTRACT_POINTS;
-                println!("Contract total: {total}");
-            }
-            (taker_points - points_for_oudlers - BASE_CONTRACT_POINTS) * self.contract.multiplier()
-        };
+        let difference = taker_points - points_for_oudlers;
+        let contract_won = difference >= HalfPoints::default();
+        let contract_points = rules.contract_points(self.contract, difference);
+        if !quiet {
+            println!(
+                "Contract difference: {difference}, counted as {}",
+                difference.rounded()
+            );
+        }
         if !quiet {
             println!(
                 "Taker contract: {} (x{})",
                 self.contract,
-                self.contract.multiplier()
+                rules.multiplier(self.contract)
             );
             println!("Taker contract points: {contract_points}");
         }
 
-        let points_petit_au_bout = 10.0 * self.contract.multiplier();
+        let points_petit_au_bout = rules.petit_au_bout(self.contract);
         let petit_au_bout_bonus = match self.petit_au_bout_for_team {
             Some(Team::Defense) => {
                 if !quiet {
@@ -394,21 +508,31 @@ impl<const MODE: usize> GameStarted<'_, MODE> {
                 if !quiet {
                     println!("No petit au bout bonus");
                 }
-                0.0
+                0
             }
         };
 
+        // RULE: handles are not multiplied, they go to the team which wins the deal
+        let mut handle_bonuses = 0;
+        for shown in &self.handles {
+            let handle_bonus = rules.handle_bonus(shown.handle());
+            if !quiet {
+                println!("Handle bonus of {shown} : {handle_bonus}");
+            }
+            handle_bonuses += handle_bonus;
+        }
+        if !contract_won {
+            handle_bonuses = -handle_bonuses;
+        }
+
         let ratio = self.mode().ratio(ally_index.is_some());
         let points = contract_points + petit_au_bout_bonus + handle_bonuses + slam_bonus;
 
         let Ok(taker) = self.player_mut(self.taker_index) else {
             return Err(TarotErrorKind::NoTaker(self.taker_index));
         };
-        if contract_points >= OrderedFloat(0.0) {
-            taker.add_score(ratio * points);
-        } else {
-            taker.add_score(-ratio * points);
-        }
+        // RULE: contract points are already negative when the contract fails
+        taker.add_score(ratio * points);
 
         if !quiet {
             println!("Total handle bonuses: {handle_bonuses}");
@@ -425,11 +549,7 @@ impl<const MODE: usize> GameStarted<'_, MODE> {
             let Ok(ally) = self.player_mut(ally_index) else {
                 return Err(TarotErrorKind::NoAlly(ally_index));
             };
-            if contract_points >= OrderedFloat(0.0) {
-                ally.add_score(points);
-            } else {
-                ally.add_score(-points);
-            }
+            ally.add_score(points);
             if !quiet {
                 println!("Ally total points: {}", ally.score());
             }
@@ -441,15 +561,41 @@ impl<const MODE: usize> GameStarted<'_, MODE> {
                 .game()
                 .player_mut(defenser_index)
                 .map_err(|_| TarotErrorKind::NoDefenser(defenser_index))?;
-            if contract_points >= OrderedFloat(0.0) {
-                defenser.add_score(-points);
-            } else {
-                defenser.add_score(points);
-            }
+            defenser.add_score(-points);
             if !quiet {
                 println!("Defenser : {}", defenser.name());
             }
         }
+        // RULE: every other player pays each misère to its announcer, whatever the result of the deal
+        let miseres = self.game_distributed.miseres().to_vec();
+        let others = i32::try_from(MODE - 1).unwrap_or(0);
+        for shown in &miseres {
+            for index in 0..MODE {
+                let points = if index == shown.seat() {
+                    rules.misere_points * others
+                } else {
+                    -rules.misere_points
+                };
+                self.player_mut(index)?.add_score(points);
+            }
+            if !quiet {
+                println!(
+                    "Misère bonus of {} from each other player for {shown}",
+                    rules.misere_points
+                );
+            }
+        }
+        let record = DealRecord::new(
+            self.taker_index,
+            self.contract,
+            self.handles.clone(),
+            miseres,
+            slam,
+            points,
+            rules,
+        );
+        self.game_distributed.game().record(record);
+        self.gather()?;
         self.is_consistent()
     }
 }
@@ -459,7 +605,7 @@ fn game_started_tests() {
     use crate::game::Game;
 
     let options = Options::default();
-    let _game = Game::<{ Mode::Four.players() }>::new(options);
+    let _game = Game::<{ Mode::Four.players() }>::new(options, 0);
 
     // let game_distributed = GameDistributed::new(game, options, )
 }
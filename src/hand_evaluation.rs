@@ -0,0 +1,178 @@
+use strum::IntoEnumIterator;
+
+use crate::card::Card;
+use crate::contract::Contract;
+use crate::deck::Deck;
+use crate::mode::Mode;
+use crate::suit::Suit;
+use crate::suit_value::SuitValue;
+use crate::trump::Trump;
+
+const TRUMP_POINTS: usize = 2;
+const MAJOR_TRUMP_POINTS: usize = 1;
+const LONG_TRUMPS_POINTS: usize = 2;
+const UNASSAILABLE_POINTS: usize = 10;
+const FOOL_POINTS: usize = 8;
+const KING_POINTS: usize = 6;
+const QUEEN_POINTS: usize = 3;
+const MARRIAGE_POINTS: usize = 1;
+const KNIGHT_POINTS: usize = 2;
+const JACK_POINTS: usize = 1;
+const VOID_POINTS: usize = 6;
+const SINGLETON_POINTS: usize = 3;
+const LONG_SUIT_POINTS: usize = 2;
+
+// classic tarot point-count of a hand, before the dog is known
+#[must_use]
+pub fn evaluate(hand: &Deck) -> usize {
+    let trumps = hand.count_trumps() - usize::from(hand.has_fool());
+    let mut score = trumps * TRUMP_POINTS;
+    score += hand
+        .iter()
+        .filter(|card| matches!(card, Card::Trump(trump) if *trump >= Trump::_16 && *trump < Trump::_21))
+        .count()
+        * MAJOR_TRUMP_POINTS;
+    score += trumps.saturating_sub(4) * LONG_TRUMPS_POINTS;
+
+    if hand.has(&Card::Trump(Trump::_21)) {
+        score += UNASSAILABLE_POINTS;
+    }
+    if hand.has_fool() {
+        score += FOOL_POINTS;
+    }
+    // RULE: the petit is only worth something if enough trumps protect it
+    if hand.has_petit() {
+        score += match trumps - 1 {
+            0..=3 => 0,
+            4 => 5,
+            _ => 8,
+        };
+    }
+
+    score += hand.count_tete(SuitValue::King) * KING_POINTS;
+    score += hand.count_tete(SuitValue::Queen) * QUEEN_POINTS;
+    score += hand.count_tete(SuitValue::Knight) * KNIGHT_POINTS;
+    score += hand.count_tete(SuitValue::Jack) * JACK_POINTS;
+
+    for suit in Suit::iter() {
+        let length = hand.count_suit(suit);
+        if hand.has(&Card::normal(suit, SuitValue::King))
+            && hand.has(&Card::normal(suit, SuitValue::Queen))
+        {
+            score += MARRIAGE_POINTS;
+        }
+        // short suits are only worth something with trumps to cut
+        if trumps >= 4 {
+            match length {
+                0 => score += VOID_POINTS,
+                1 => score += SINGLETON_POINTS,
+                _ => {}
+            }
+        }
+        score += length.saturating_sub(4) * LONG_SUIT_POINTS;
+    }
+    score
+}
+
+#[must_use]
+pub const fn threshold(mode: Mode, contract: Contract) -> usize {
+    match (mode, contract) {
+        (Mode::Two, Contract::Petite) => 58,
+        (Mode::Two, Contract::Garde) => 78,
+        (Mode::Two, Contract::GardeSans) => 95,
+        (Mode::Two, Contract::GardeContre) => 108,
+        (Mode::Three, Contract::Petite) => 50,
+        (Mode::Three, Contract::Garde) => 68,
+        (Mode::Three, Contract::GardeSans) => 85,
+        (Mode::Three, Contract::GardeContre) => 96,
+        (Mode::Four, Contract::Petite) => 40,
+        (Mode::Four, Contract::Garde) => 56,
+        (Mode::Four, Contract::GardeSans) => 71,
+        (Mode::Four, Contract::GardeContre) => 81,
+        (Mode::Five, Contract::Petite) => 34,
+        (Mode::Five, Contract::Garde) => 46,
+        (Mode::Five, Contract::GardeSans) => 60,
+        (Mode::Five, Contract::GardeContre) => 70,
+        (Mode::Six, contract) => threshold(mode.seated(), contract),
+    }
+}
+
+// highest contract the hand is worth, whatever the auction
+#[must_use]
+pub fn contract_for(mode: Mode, hand: &Deck) -> Option<Contract> {
+    let score = evaluate(hand);
+    // RULE: without any oudler the taker needs the dog, so no garde sans or garde contre
+    let highest = if hand.count_oudlers() == 0 {
+        Contract::Garde
+    } else {
+        Contract::GardeContre
+    };
+    Contract::iter()
+        .filter(|contract| *contract <= highest)
+        .rfind(|contract| score >= threshold(mode, *contract))
+}
+
+#[test]
+fn hand_evaluation_tests() {
+    let weak = Deck::new(vec![
+        Card::Trump(Trump::_2),
+        Card::Trump(Trump::_5),
+        Card::normal(Suit::Heart, SuitValue::_3),
+        Card::normal(Suit::Heart, SuitValue::_7),
+        Card::normal(Suit::Spade, SuitValue::_2),
+        Card::normal(Suit::Spade, SuitValue::_9),
+        Card::normal(Suit::Spade, SuitValue::Jack),
+        Card::normal(Suit::Diamond, SuitValue::_1),
+        Card::normal(Suit::Diamond, SuitValue::_4),
+        Card::normal(Suit::Diamond, SuitValue::_8),
+        Card::normal(Suit::Diamond, SuitValue::_10),
+        Card::normal(Suit::Club, SuitValue::_2),
+        Card::normal(Suit::Club, SuitValue::_3),
+        Card::normal(Suit::Club, SuitValue::_5),
+        Card::normal(Suit::Club, SuitValue::_6),
+        Card::normal(Suit::Club, SuitValue::_9),
+        Card::normal(Suit::Heart, SuitValue::_10),
+        Card::normal(Suit::Spade, SuitValue::_6),
+    ]);
+    assert_eq!(contract_for(Mode::Four, &weak), None);
+
+    let strong = Deck::new(vec![
+        Card::Trump(Trump::Fool),
+        Card::Trump(Trump::Petit),
+        Card::Trump(Trump::_21),
+        Card::Trump(Trump::_20),
+        Card::Trump(Trump::_18),
+        Card::Trump(Trump::_17),
+        Card::Trump(Trump::_12),
+        Card::Trump(Trump::_9),
+        Card::Trump(Trump::_7),
+        Card::Trump(Trump::_3),
+        Card::normal(Suit::Heart, SuitValue::King),
+        Card::normal(Suit::Heart, SuitValue::Queen),
+        Card::normal(Suit::Heart, SuitValue::_5),
+        Card::normal(Suit::Spade, SuitValue::King),
+        Card::normal(Suit::Spade, SuitValue::_4),
+        Card::normal(Suit::Diamond, SuitValue::King),
+        Card::normal(Suit::Diamond, SuitValue::_2),
+        Card::normal(Suit::Diamond, SuitValue::_6),
+    ]);
+    assert!(contract_for(Mode::Four, &strong) >= Some(Contract::GardeSans));
+    assert!(contract_for(Mode::Three, &strong) < contract_for(Mode::Five, &strong));
+    assert_eq!(
+        contract_for(Mode::Six, &strong),
+        contract_for(Mode::Five, &strong)
+    );
+
+    let mut no_oudler: Vec<Card> = Trump::iter()
+        .filter(|trump| *trump >= Trump::_6 && *trump <= Trump::_20)
+        .map(Card::Trump)
+        .collect();
+    no_oudler.extend([
+        Card::normal(Suit::Heart, SuitValue::King),
+        Card::normal(Suit::Spade, SuitValue::King),
+        Card::normal(Suit::Diamond, SuitValue::King),
+    ]);
+    let no_oudler = Deck::new(no_oudler);
+    assert!(evaluate(&no_oudler) >= threshold(Mode::Four, Contract::GardeContre));
+    assert_eq!(contract_for(Mode::Four, &no_oudler), Some(Contract::Garde));
+}
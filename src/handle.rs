@@ -1,8 +1,11 @@
-use crate::points::Points;
-use ordered_float::OrderedFloat;
+use derive_new::new;
+use std::fmt;
 use strum::{Display, EnumIter};
 
+use crate::deck::Deck;
+
 #[derive(Default, Display, Eq, PartialEq, Debug, Copy, Clone, EnumIter)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[repr(u32)]
 pub enum Handle {
     #[default]
@@ -12,13 +15,36 @@ pub enum Handle {
     Triple,
 }
 
-impl Points for Handle {
-    fn points(&self) -> OrderedFloat<f64> {
-        OrderedFloat(match self {
-            Self::Refused => 0.0,
-            Self::Simple => 20.0,
-            Self::Double => 30.0,
-            Self::Triple => 40.0,
-        })
+/// A handle announced at the first turn, its trumps are public for the rest of the deal
+#[derive(new, Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ShownHandle {
+    seat: usize,
+    handle: Handle,
+    cards: Deck,
+}
+
+impl ShownHandle {
+    #[must_use]
+    pub const fn seat(&self) -> usize {
+        self.seat
+    }
+    #[must_use]
+    pub const fn handle(&self) -> Handle {
+        self.handle
+    }
+    #[must_use]
+    pub const fn cards(&self) -> &Deck {
+        &self.cards
+    }
+}
+
+impl fmt::Display for ShownHandle {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{} handle of seat {} : {}",
+            self.handle, self.seat, self.cards
+        )
     }
 }
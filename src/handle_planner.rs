@@ -0,0 +1,145 @@
+use crate::card::Card;
+use crate::contract::Contract;
+use crate::hand_evaluation;
+use crate::handle::Handle;
+use crate::player_in_game::PlayerInGame;
+use crate::traits::Power;
+use crate::trump::Trump;
+
+// chances are in percent
+const TAKER_SPREAD: i32 = 40;
+const ATTACK_CHANCE: i32 = 60;
+const EXTRA_TRUMP_CHANCE: i32 = 3;
+const LEAK_PER_TRUMP: f64 = 0.5;
+const EXCUSE_PENALTY: f64 = 4.0;
+
+// chance for the team of the player to win the deal, as seen from its hand
+fn win_chance(in_game: &PlayerInGame) -> i32 {
+    let mode = *in_game.mode();
+    let hand = in_game.hand();
+    if in_game.is_taker() {
+        let contract = in_game.knowledge().contract().unwrap_or(Contract::Petite);
+        let score = i32::try_from(hand_evaluation::evaluate(hand)).unwrap_or(i32::MAX);
+        let threshold =
+            i32::try_from(hand_evaluation::threshold(mode, contract)).unwrap_or(i32::MAX);
+        let margin = score.saturating_sub(threshold);
+        return (50 + margin * 50 / TAKER_SPREAD).clamp(5, 95);
+    }
+    // RULE: there are 21 trumps besides the fool
+    let share = 21 / i32::try_from(mode.players()).unwrap_or(1);
+    let trumps = i32::try_from(hand.count_trumps()).unwrap_or(0);
+    let extra = (trumps - share) * EXTRA_TRUMP_CHANCE;
+    let chance = if in_game.is_attack() {
+        ATTACK_CHANCE + extra
+    } else {
+        100 - ATTACK_CHANCE + extra
+    };
+    chance.clamp(5, 95)
+}
+
+// expected points of a handle, minus what showing the trumps tells to the other players
+fn value(in_game: &PlayerInGame, trumps: &[Card], handle: Handle, chance: i32) -> f64 {
+    if handle == Handle::Refused {
+        return 0.0;
+    }
+    let rules = &in_game.options().rules;
+    let limit = rules.handle_limit(*in_game.mode(), handle);
+    let fool = trumps.iter().any(|card| card.is_fool());
+    let without_fool = trumps.len() - usize::from(fool);
+    // RULE: the handle bonus goes to the team which wins the deal
+    let expected = f64::from(rules.handle_bonus(handle) * (2 * chance - 100)) / 100.0;
+    let leak = f64::from(u32::try_from(limit).unwrap_or(u32::MAX)) * LEAK_PER_TRUMP;
+    let excuse = if fool && without_fool < limit {
+        EXCUSE_PENALTY
+    } else {
+        0.0
+    };
+    expected - leak - excuse
+}
+
+// handle worth announcing among the available ones, refused when none pays
+#[must_use]
+pub fn choose(in_game: &PlayerInGame, trumps: &[Card], handles: &[Handle]) -> Handle {
+    let chance = win_chance(in_game);
+    handles
+        .iter()
+        .map(|handle| (*handle, value(in_game, trumps, *handle, chance)))
+        .filter(|(_, value)| *value > 0.0)
+        .max_by(|(_, a), (_, b)| a.total_cmp(b))
+        .map_or(Handle::Refused, |(handle, _)| handle)
+}
+
+// index of the trump telling the most about the hand : the petit, then the highest ones
+#[must_use]
+pub fn hide(trumps: &[Card]) -> Option<usize> {
+    trumps
+        .iter()
+        .position(|card| *card == Card::Trump(Trump::Petit))
+        .or_else(|| {
+            trumps
+                .iter()
+                .enumerate()
+                .max_by_key(|(_, card)| card.power())
+                .map(|(index, _)| index)
+        })
+}
+
+#[test]
+fn handle_planner_tests() {
+    use crate::deck::Deck;
+    use crate::mode::Mode;
+    use crate::options::Options;
+    use crate::role::Role;
+    use crate::suit::Suit;
+    use crate::suit_value::SuitValue;
+    use crate::team::Team;
+
+    let trumps: Vec<Card> = [
+        Trump::Fool,
+        Trump::Petit,
+        Trump::_21,
+        Trump::_20,
+        Trump::_19,
+        Trump::_17,
+        Trump::_15,
+        Trump::_12,
+        Trump::_9,
+        Trump::_7,
+        Trump::_4,
+        Trump::_2,
+    ]
+    .into_iter()
+    .map(Card::Trump)
+    .collect();
+    let mut hand = Deck::new(trumps.clone());
+    hand.extend(&Deck::new(vec![
+        Card::normal(Suit::Heart, SuitValue::King),
+        Card::normal(Suit::Heart, SuitValue::Queen),
+        Card::normal(Suit::Spade, SuitValue::King),
+        Card::normal(Suit::Club, SuitValue::_3),
+        Card::normal(Suit::Club, SuitValue::_4),
+        Card::normal(Suit::Diamond, SuitValue::_6),
+    ]));
+    let handles = [Handle::Refused, Handle::Simple];
+
+    let mut taker = PlayerInGame::new(Mode::Four, Options::default(), 0);
+    taker.extend_hand(&hand);
+    taker.set_role(Role::Taker);
+    taker.set_team(Team::Attack);
+    taker.knowledge_mut().set_contract(Contract::Garde);
+    assert_eq!(choose(&taker, &trumps, &handles), Handle::Simple);
+
+    // a defender barely favoured keeps its trumps hidden
+    let mut defenser = PlayerInGame::new(Mode::Four, Options::default(), 1);
+    let weak: Vec<Card> = trumps.iter().copied().take(10).collect();
+    defenser.extend_hand(&Deck::new(weak.clone()));
+    defenser.set_role(Role::Defenser);
+    defenser.set_team(Team::Defense);
+    assert_eq!(choose(&defenser, &weak, &handles), Handle::Refused);
+
+    assert_eq!(hide(&trumps), Some(1));
+    assert_eq!(
+        hide(&[Card::Trump(Trump::_3), Card::Trump(Trump::_18)]),
+        Some(1)
+    );
+}
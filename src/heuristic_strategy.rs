@@ -0,0 +1,155 @@
+use derive_new::new;
+use rand::rngs::StdRng;
+
+use crate::call_planner;
+use crate::card::Card;
+use crate::card_play;
+use crate::contract::Contract;
+use crate::deck::Deck;
+use crate::discard_planner;
+use crate::errors::TarotErrorKind;
+use crate::hand_evaluation;
+use crate::handle::Handle;
+use crate::handle_planner;
+use crate::koenigrufen;
+use crate::misere::Misere;
+use crate::player::Player;
+use crate::player_in_game::PlayerInGame;
+use crate::random_strategy::RandomStrategy;
+use crate::slam_planner;
+use crate::strategy::PlayerStrategy;
+use crate::tarock_contract::TarockContract;
+use crate::tarock_strategy::TarockStrategy;
+use crate::turn::Turn;
+
+/// Rule of thumb bot, decisions without a heuristic yet are taken randomly
+#[derive(new, Debug, Default, Copy, Clone)]
+pub struct HeuristicStrategy {
+    // RULE: in attack mode, bots always pass and let the human take
+    attack: bool,
+    #[new(default)]
+    random: RandomStrategy,
+}
+
+impl PlayerStrategy for HeuristicStrategy {
+    fn bid(
+        &self,
+        _player: &Player,
+        in_game: &PlayerInGame,
+        contracts: &[Contract],
+        _rng: &mut StdRng,
+    ) -> Result<Option<Contract>, TarotErrorKind> {
+        if self.attack {
+            return Ok(None);
+        }
+        let wanted = hand_evaluation::contract_for(*in_game.mode(), in_game.hand());
+        Ok(wanted.filter(|contract| contracts.contains(contract)))
+    }
+    fn slam(
+        &self,
+        _player: &Player,
+        in_game: &PlayerInGame,
+        _rng: &mut StdRng,
+    ) -> Result<bool, TarotErrorKind> {
+        Ok(slam_planner::estimate(in_game).announce())
+    }
+    // showing the hand only costs something to a player who would take
+    fn misere(
+        &self,
+        _player: &Player,
+        in_game: &PlayerInGame,
+        _misere: Misere,
+        _rng: &mut StdRng,
+    ) -> Result<bool, TarotErrorKind> {
+        Ok(hand_evaluation::contract_for(*in_game.mode(), in_game.hand()).is_none())
+    }
+    fn handle(
+        &self,
+        _player: &Player,
+        in_game: &PlayerInGame,
+        trumps: &[Card],
+        handles: &[Handle],
+        _rng: &mut StdRng,
+    ) -> Result<Handle, TarotErrorKind> {
+        Ok(handle_planner::choose(in_game, trumps, handles))
+    }
+    fn hide_trump(
+        &self,
+        player: &Player,
+        in_game: &PlayerInGame,
+        trumps: &[Card],
+        limit: usize,
+        rng: &mut StdRng,
+    ) -> Result<usize, TarotErrorKind> {
+        handle_planner::hide(trumps).map_or_else(
+            || self.random.hide_trump(player, in_game, trumps, limit, rng),
+            Ok,
+        )
+    }
+    fn call(
+        &self,
+        player: &Player,
+        in_game: &PlayerInGame,
+        choices: &[Card],
+        rng: &mut StdRng,
+    ) -> Result<Card, TarotErrorKind> {
+        call_planner::choose(in_game.hand(), choices)
+            .map_or_else(|| self.random.call(player, in_game, choices, rng), Ok)
+    }
+    fn explain_discard(&self, in_game: &PlayerInGame) -> Option<String> {
+        discard_planner::plan(in_game.hand(), in_game.left_to_discard())
+            .map(|plan| plan.to_string())
+    }
+    fn discard(
+        &self,
+        player: &Player,
+        in_game: &PlayerInGame,
+        discardables: &[usize],
+        rng: &mut StdRng,
+    ) -> Result<usize, TarotErrorKind> {
+        let hand = in_game.hand();
+        if let Some(plan) = discard_planner::plan(hand, in_game.left_to_discard())
+            && let Some(index) = discardables
+                .iter()
+                .find(|index| hand.get(**index).is_some_and(|card| plan.cards().has(card)))
+        {
+            return Ok(*index);
+        }
+        self.random.discard(player, in_game, discardables, rng)
+    }
+    fn play(
+        &self,
+        _player: &Player,
+        in_game: &PlayerInGame,
+        turn: &Turn,
+        choices: &[usize],
+        _rng: &mut StdRng,
+    ) -> Result<usize, TarotErrorKind> {
+        card_play::play(in_game, turn, choices)
+    }
+}
+
+impl TarockStrategy for HeuristicStrategy {
+    fn tarock_bid(
+        &self,
+        _player: &Player,
+        in_game: &PlayerInGame,
+        contracts: &[TarockContract],
+        _rng: &mut StdRng,
+    ) -> Result<Option<TarockContract>, TarotErrorKind> {
+        if self.attack {
+            return Ok(None);
+        }
+        let wanted = koenigrufen::contract_for(in_game.hand());
+        Ok(wanted.filter(|contract| contracts.contains(contract)))
+    }
+    fn talon(
+        &self,
+        _player: &Player,
+        _in_game: &PlayerInGame,
+        halves: &[Deck],
+        _rng: &mut StdRng,
+    ) -> Result<usize, TarotErrorKind> {
+        Ok(koenigrufen::best_half(halves))
+    }
+}
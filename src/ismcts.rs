@@ -0,0 +1,647 @@
+use derive_new::new;
+use rand::Rng;
+use rand::rngs::StdRng;
+use rand::seq::{IndexedRandom, SliceRandom};
+use std::time::{Duration, Instant};
+use strum::IntoEnumIterator;
+
+use crate::call_planner;
+use crate::card::Card;
+use crate::contract::Contract;
+use crate::deck::Deck;
+use crate::discard_planner;
+use crate::errors::TarotErrorKind;
+use crate::knowledge::Voids;
+use crate::mode::Mode;
+use crate::player_in_game::PlayerInGame;
+use crate::simulation::Simulation;
+use crate::suit::Suit;
+use crate::suit_value::SuitValue;
+use crate::team::Team;
+use crate::turn::Turn;
+
+const EXPLORATION: f64 = 0.7;
+const SAMPLING_ATTEMPTS: usize = 20;
+const ROOT: usize = 0;
+
+/// Limits of a search, it stops at the first one reached
+#[derive(new, Debug, Default, Copy, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Budget {
+    iterations: usize,
+    time: Option<Duration>,
+}
+
+impl Budget {
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.iterations == 0
+    }
+    fn exhausted(&self, iteration: usize, start: Instant) -> bool {
+        iteration >= self.iterations || self.time.is_some_and(|time| start.elapsed() >= time)
+    }
+}
+
+// hidden cards dealt to every holder, respecting the constraints when possible
+fn deal_hidden(
+    pool: &[Card],
+    capacities: &[usize],
+    voids: &[Voids],
+    rng: &mut StdRng,
+) -> Vec<Deck> {
+    let holders = capacities.len();
+    for _ in 0..SAMPLING_ATTEMPTS {
+        let mut remaining = capacities.to_vec();
+        let mut cards = pool.to_vec();
+        cards.shuffle(rng);
+        // most constrained cards first
+        cards.sort_by_cached_key(|card| voids.iter().filter(|voids| voids.allows(*card)).count());
+        let mut dealt = vec![Deck::default(); holders];
+        let complete = cards.iter().all(|card| {
+            let eligible: Vec<usize> = (0..holders)
+                .filter(|holder| {
+                    remaining.get(*holder).is_some_and(|left| *left > 0)
+                        && voids.get(*holder).is_none_or(|voids| voids.allows(*card))
+                })
+                .collect();
+            let Ok(holder) = eligible
+                .choose_weighted(rng, |holder| remaining.get(*holder).copied().unwrap_or(0))
+            else {
+                return false;
+            };
+            if let (Some(left), Some(deck)) = (remaining.get_mut(*holder), dealt.get_mut(*holder)) {
+                *left -= 1;
+                deck.push(*card);
+            }
+            true
+        });
+        if complete {
+            return dealt;
+        }
+    }
+    // no consistent deal found, forget what was deduced
+    let mut cards = pool.to_vec();
+    cards.shuffle(rng);
+    let mut cards = cards.into_iter();
+    capacities
+        .iter()
+        .map(|capacity| Deck::new(cards.by_ref().take(*capacity).collect()))
+        .collect()
+}
+
+fn hand_capacities(mode: Mode, me: usize, hand: &Deck) -> Vec<usize> {
+    (0..mode.players())
+        .map(|seat| if seat == me { 0 } else { hand.len() })
+        .collect()
+}
+
+fn ally_for(callee: Option<Card>, taker: usize, hands: &[Deck]) -> Option<usize> {
+    let callee = callee?;
+    hands
+        .iter()
+        .position(|hand| hand.has(&callee))
+        .filter(|seat| *seat != taker)
+}
+
+// sample a whole deal consistent with what the seat has seen so far
+fn determinize(
+    in_game: &PlayerInGame,
+    turn: &Turn,
+    rng: &mut StdRng,
+) -> Result<Simulation, TarotErrorKind> {
+    let mode = *in_game.mode();
+    let players = mode.players();
+    let me = in_game.seat();
+    let knowledge = in_game.knowledge();
+    let contract = knowledge.contract().ok_or(TarotErrorKind::NoContract(0))?;
+    let taker = in_game.taker_index().ok_or(TarotErrorKind::NoTaker(me))?;
+    let hand = in_game.hand();
+
+    let mut voids: Vec<Voids> = (0..players).map(|seat| knowledge.voids(seat)).collect();
+
+    // RULE: the discard of a petite or a garde is only known by the taker
+    let known_discard =
+        in_game.is_taker() && matches!(contract, Contract::Petite | Contract::Garde);
+    let pile = match contract {
+        Contract::Petite | Contract::Garde => {
+            if known_discard {
+                None
+            } else {
+                Some(Voids::discard())
+            }
+        }
+        Contract::GardeSans | Contract::GardeContre => Some(Voids::default()),
+    };
+    let mut capacities = hand_capacities(mode, me, hand);
+    for (seat, _) in turn.plays() {
+        if let Some(capacity) = capacities.get_mut(seat) {
+            *capacity = capacity.saturating_sub(1);
+        }
+    }
+    if let Some(pile) = &pile {
+        capacities.push(mode.dog_size());
+        voids.push(pile.clone());
+    }
+
+    let mut pool: Vec<Card> = Deck::full()
+        .iter()
+        .filter(|card| {
+            let seen = hand.has(card)
+                || knowledge.is_played(**card)
+                || (known_discard && in_game.discarded().has(card));
+            !seen
+        })
+        .copied()
+        .collect();
+
+    // shown cards are certainly still in their hands
+    let mut hands = vec![Deck::default(); players];
+    let mut forced: Vec<(usize, Card)> = knowledge
+        .handles()
+        .iter()
+        .filter(|shown| shown.seat() != me)
+        .flat_map(|shown| shown.cards().iter().map(|card| (shown.seat(), *card)))
+        .collect();
+    // the taker may have discarded some cards of its misère
+    forced.extend(
+        knowledge
+            .miseres()
+            .iter()
+            .filter(|shown| shown.seat() != me && shown.seat() != taker)
+            .flat_map(|shown| shown.cards().iter().map(|card| (shown.seat(), *card))),
+    );
+    // RULE: kings and trumps of the dog cannot be discarded
+    if let Some(dog) = knowledge.dog()
+        && taker != me
+    {
+        forced.extend(
+            dog.iter()
+                .filter(|card| {
+                    card.is_trump()
+                        || matches!(card, Card::Normal(normal) if normal.value() == &SuitValue::King)
+                })
+                .map(|card| (taker, *card)),
+        );
+    }
+    for (seat, card) in forced {
+        let Some(position) = pool.iter().position(|hidden| *hidden == card) else {
+            continue;
+        };
+        if let (Some(capacity), Some(hand)) = (capacities.get_mut(seat), hands.get_mut(seat))
+            && *capacity > 0
+        {
+            *capacity -= 1;
+            hand.push(pool.remove(position));
+        }
+    }
+    // RULE: everyone saw the dog, its other cards were kept by the taker or discarded
+    let mut discarded = Deck::default();
+    if let Some(dog) = knowledge.dog()
+        && pile.is_some()
+    {
+        let mut shown: Vec<Card> = dog
+            .iter()
+            .filter(|card| pool.contains(card))
+            .copied()
+            .collect();
+        shown.shuffle(rng);
+        for card in shown {
+            let kept = capacities.get(taker).copied().unwrap_or_default();
+            let Some(room) = capacities.get(players).copied() else {
+                continue;
+            };
+            if kept + room == 0 {
+                continue;
+            }
+            let Some(position) = pool.iter().position(|hidden| *hidden == card) else {
+                continue;
+            };
+            let seat = if rng.random_range(0..kept + room) < kept {
+                taker
+            } else {
+                players
+            };
+            if let Some(capacity) = capacities.get_mut(seat) {
+                *capacity -= 1;
+            }
+            match hands.get_mut(seat) {
+                Some(hand) => hand.push(pool.remove(position)),
+                None => discarded.push(pool.remove(position)),
+            }
+        }
+    }
+    if pool.len() != capacities.iter().sum::<usize>() {
+        return Err(TarotErrorKind::InvalidCase(format!(
+            "{} hidden cards for {} places",
+            pool.len(),
+            capacities.iter().sum::<usize>()
+        )));
+    }
+
+    let mut dealt = deal_hidden(&pool, &capacities, &voids, rng);
+    let hidden_pile = if pile.is_some() {
+        dealt.pop().map(|mut hidden_pile| {
+            hidden_pile.extend(&discarded);
+            hidden_pile
+        })
+    } else {
+        None
+    };
+    for (hand, hidden) in hands.iter_mut().zip(dealt) {
+        hand.extend(&hidden);
+    }
+    if let Some(own) = hands.get_mut(me) {
+        own.extend(hand);
+    }
+
+    let callee = in_game.callee();
+    let ally = callee
+        .and_then(|callee| knowledge.played_by(callee))
+        .filter(|seat| *seat != taker)
+        .or_else(|| ally_for(callee, taker, &hands));
+    let rules = in_game.options().rules;
+    let mut simulation = Simulation::new(mode, contract, rules, taker, ally, callee, &hands);
+    let pile_team = if contract == Contract::GardeContre {
+        Team::Defense
+    } else {
+        Team::Attack
+    };
+    match hidden_pile {
+        Some(hidden_pile) => simulation.bank(&hidden_pile, pile_team),
+        None => simulation.bank(in_game.discarded(), pile_team),
+    }
+    for trick in knowledge.turns() {
+        simulation.gather(trick, false)?;
+    }
+    simulation.resume(turn.clone(), me);
+    Ok(simulation)
+}
+
+#[derive(Debug)]
+struct Node {
+    card: Option<Card>,
+    seat: usize,
+    children: Vec<usize>,
+    visits: f64,
+    availability: f64,
+    reward: f64,
+}
+
+impl Node {
+    const fn new(card: Option<Card>, seat: usize) -> Self {
+        Self {
+            card,
+            seat,
+            children: Vec::new(),
+            visits: 0.0,
+            availability: 0.0,
+            reward: 0.0,
+        }
+    }
+    fn upper_bound(&self) -> f64 {
+        EXPLORATION.mul_add(
+            (self.availability.ln() / self.visits).sqrt(),
+            self.reward / self.visits,
+        )
+    }
+}
+
+struct Tree {
+    nodes: Vec<Node>,
+}
+
+impl Tree {
+    fn node(&self, index: usize) -> Result<&Node, TarotErrorKind> {
+        self.nodes
+            .get(index)
+            .ok_or_else(|| TarotErrorKind::InvalidCase(format!("no node {index}")))
+    }
+    fn node_mut(&mut self, index: usize) -> Result<&mut Node, TarotErrorKind> {
+        self.nodes
+            .get_mut(index)
+            .ok_or_else(|| TarotErrorKind::InvalidCase(format!("no node {index}")))
+    }
+    fn child(&self, parent: usize, card: Card) -> Result<Option<usize>, TarotErrorKind> {
+        for child in &self.node(parent)?.children {
+            if self.node(*child)?.card == Some(card) {
+                return Ok(Some(*child));
+            }
+        }
+        Ok(None)
+    }
+    fn expand(&mut self, parent: usize, card: Card, seat: usize) -> Result<usize, TarotErrorKind> {
+        let child = self.nodes.len();
+        self.node_mut(parent)?.children.push(child);
+        self.nodes.push(Node::new(Some(card), seat));
+        Ok(child)
+    }
+    // among the children playable in this determinization, the most promising one
+    fn select(&mut self, parent: usize, moves: &[Card]) -> Result<Option<usize>, TarotErrorKind> {
+        let mut best: Option<(usize, f64)> = None;
+        for card in moves {
+            let Some(child) = self.child(parent, *card)? else {
+                continue;
+            };
+            let node = self.node_mut(child)?;
+            node.availability += 1.0;
+            let bound = node.upper_bound();
+            if best.is_none_or(|(_, best_bound)| bound > best_bound) {
+                best = Some((child, bound));
+            }
+        }
+        Ok(best.map(|(child, _)| child))
+    }
+    fn most_visited(&self, parent: usize) -> Result<Option<Card>, TarotErrorKind> {
+        let mut best: Option<&Node> = None;
+        for child in &self.node(parent)?.children {
+            let node = self.node(*child)?;
+            if best.is_none_or(|best| node.visits > best.visits) {
+                best = Some(node);
+            }
+        }
+        Ok(best.and_then(|node| node.card))
+    }
+}
+
+// card to play, searched over the deals the seat cannot tell apart
+pub fn play(
+    in_game: &PlayerInGame,
+    turn: &Turn,
+    budget: Budget,
+    rng: &mut StdRng,
+) -> Result<Option<Card>, TarotErrorKind> {
+    let start = Instant::now();
+    let mut tree = Tree {
+        nodes: vec![Node::new(None, in_game.seat())],
+    };
+    let mut iteration = 0;
+    while !budget.exhausted(iteration, start) {
+        iteration += 1;
+        let mut simulation = determinize(in_game, turn, rng)?;
+        let mut path = vec![ROOT];
+        let mut current = ROOT;
+        while !simulation.finished() {
+            let moves = simulation.moves()?;
+            let seat = simulation.current();
+            let mut untried = Vec::new();
+            for card in &moves {
+                if tree.child(current, *card)?.is_none() {
+                    untried.push(*card);
+                }
+            }
+            if let Some(card) = untried.choose(rng) {
+                simulation.play(*card)?;
+                current = tree.expand(current, *card, seat)?;
+                path.push(current);
+                break;
+            }
+            let Some(child) = tree.select(current, &moves)? else {
+                break;
+            };
+            let card = tree
+                .node(child)?
+                .card
+                .ok_or(TarotErrorKind::NoCard(child))?;
+            simulation.play(card)?;
+            current = child;
+            path.push(current);
+        }
+        simulation.rollout()?;
+        for index in path {
+            let node = tree.node_mut(index)?;
+            node.visits += 1.0;
+            node.reward += simulation.reward(node.seat);
+        }
+    }
+    tree.most_visited(ROOT)
+}
+
+// the rest of the discard, as planned by the heuristic bot
+fn complete_discard(hand: &mut Deck, count: usize) -> Deck {
+    let Some(plan) = discard_planner::plan(hand, count) else {
+        return Deck::default();
+    };
+    for card in plan.cards().iter() {
+        if let Some(index) = hand.iter().position(|held| held == card) {
+            hand.remove(index);
+        }
+    }
+    plan.cards().clone()
+}
+
+// outcome for the taker of a deal where only the other hands are unknown
+fn simulate_taker(
+    in_game: &PlayerInGame,
+    contract: Contract,
+    hand: Deck,
+    pile: &Deck,
+    rng: &mut StdRng,
+) -> Result<Simulation, TarotErrorKind> {
+    let mode = *in_game.mode();
+    let me = in_game.seat();
+    let pool: Vec<Card> = Deck::full()
+        .iter()
+        .filter(|card| !hand.has(card) && !pile.has(card))
+        .copied()
+        .collect();
+    let capacities = hand_capacities(mode, me, &hand);
+    let mut hands = deal_hidden(&pool, &capacities, &[], rng);
+    if let Some(own) = hands.get_mut(me) {
+        *own = hand;
+    }
+    let callee = in_game
+        .callee()
+        .or_else(|| callee_for(mode, in_game.hand()));
+    let ally = ally_for(callee, me, &hands);
+    let rules = in_game.options().rules;
+    let mut simulation = Simulation::new(mode, contract, rules, me, ally, callee, &hands);
+    if contract != Contract::GardeContre {
+        simulation.bank(pile, Team::Attack);
+    }
+    simulation.rollout()?;
+    Ok(simulation)
+}
+
+// index in hand of the next card to discard, the rest of the discard being the lowest cards
+pub fn discard(
+    in_game: &PlayerInGame,
+    discardables: &[usize],
+    budget: Budget,
+    rng: &mut StdRng,
+) -> Result<Option<usize>, TarotErrorKind> {
+    let contract = in_game
+        .knowledge()
+        .contract()
+        .ok_or(TarotErrorKind::NoContract(0))?;
+    let left = in_game.left_to_discard().saturating_sub(1);
+    let start = Instant::now();
+    let mut stats = vec![(0.0, 0.0); discardables.len()];
+    let mut iteration = 0;
+    while !budget.exhausted(iteration, start) {
+        iteration += 1;
+        let total = f64::from(u32::try_from(iteration).unwrap_or(u32::MAX));
+        let Some(arm) = stats
+            .iter()
+            .enumerate()
+            .map(|(arm, (visits, reward)): (usize, &(f64, f64))| {
+                if *visits == 0.0 {
+                    (arm, f64::MAX)
+                } else {
+                    (
+                        arm,
+                        EXPLORATION.mul_add((total.ln() / visits).sqrt(), reward / visits),
+                    )
+                }
+            })
+            .max_by(|(_, a), (_, b)| a.total_cmp(b))
+            .map(|(arm, _)| arm)
+        else {
+            return Ok(None);
+        };
+        let index = *discardables.get(arm).ok_or(TarotErrorKind::NoCard(arm))?;
+        let mut hand = in_game.hand().clone();
+        let mut pile = in_game.discarded().clone();
+        pile.push(hand.remove(index));
+        pile.extend(&complete_discard(&mut hand, left));
+        let simulation = simulate_taker(in_game, contract, hand, &pile, rng)?;
+        if let Some((visits, reward)) = stats.get_mut(arm) {
+            *visits += 1.0;
+            *reward += simulation.reward(in_game.seat());
+        }
+    }
+    Ok(stats
+        .iter()
+        .zip(discardables)
+        .filter(|((visits, _), _)| *visits > 0.0)
+        .max_by(|((a, _), _), ((b, _), _)| a.total_cmp(b))
+        .map(|(_, index)| *index))
+}
+
+// card the heuristic taker would call, among the kings or the next values when they are all held
+fn callee_for(mode: Mode, hand: &Deck) -> Option<Card> {
+    if mode != Mode::Five {
+        return None;
+    }
+    let mut choices = Vec::new();
+    for value in [
+        SuitValue::King,
+        SuitValue::Queen,
+        SuitValue::Knight,
+        SuitValue::Jack,
+    ] {
+        choices.extend(Suit::iter().map(|suit| Card::normal(suit, value)));
+        if hand.count_tete(value) < 4 {
+            break;
+        }
+    }
+    call_planner::choose(hand, &choices)
+}
+
+// contract with the best mean outcome over sampled deals, None when passing is better
+pub fn bid(
+    in_game: &PlayerInGame,
+    contracts: &[Contract],
+    budget: Budget,
+    rng: &mut StdRng,
+) -> Result<Option<Contract>, TarotErrorKind> {
+    let mode = *in_game.mode();
+    let dog_size = mode.dog_size();
+    let start = Instant::now();
+    let mut totals = vec![0.0; contracts.len()];
+    let mut iteration = 0;
+    while !budget.exhausted(iteration, start) {
+        iteration += 1;
+        let mut unknown: Vec<Card> = Deck::full()
+            .iter()
+            .filter(|card| !in_game.hand().has(card))
+            .copied()
+            .collect();
+        unknown.shuffle(rng);
+        let dog = Deck::new(unknown.drain(..dog_size.min(unknown.len())).collect());
+        for (contract, total) in contracts.iter().zip(totals.iter_mut()) {
+            let mut hand = in_game.hand().clone();
+            let pile = match contract {
+                Contract::Petite | Contract::Garde => {
+                    hand.extend(&dog);
+                    complete_discard(&mut hand, dog_size)
+                }
+                Contract::GardeSans | Contract::GardeContre => dog.clone(),
+            };
+            *total += f64::from(simulate_taker(in_game, *contract, hand, &pile, rng)?.score());
+        }
+    }
+    Ok(contracts
+        .iter()
+        .zip(totals)
+        .filter(|(_, total)| *total > 0.0)
+        .max_by(|(_, a), (_, b)| a.total_cmp(b))
+        .map(|(contract, _)| *contract))
+}
+
+#[test]
+fn ismcts_tests() -> Result<(), TarotErrorKind> {
+    use crate::game::Game;
+    use crate::ismcts_strategy::IsmctsStrategy;
+    use crate::knowledge::Knowledge;
+    use crate::options::Options;
+    use crate::strategy::PlayerStrategy;
+    use crate::trump::Trump;
+
+    fn deal<const MODE: usize>(options: Options) -> Result<(), TarotErrorKind> {
+        let strategies: [Box<dyn PlayerStrategy>; MODE] = array_init::array_init(|_| {
+            Box::new(IsmctsStrategy::new(false, Budget::new(10, None))) as Box<dyn PlayerStrategy>
+        });
+        Game::<MODE>::with_strategies(options, 3, strategies)?.start(2)
+    }
+    let options = Options {
+        quiet: true,
+        test: true,
+        ..Options::default()
+    };
+    deal::<{ Mode::Three.players() }>(options)?;
+    deal::<{ Mode::Four.players() }>(options)?;
+    deal::<{ Mode::Five.players() }>(options)?;
+
+    // a seat which did not follow hearts holds no heart and no trump
+    let mut knowledge = Knowledge::default();
+    let mut turn = Turn::default();
+    turn.put(0, Card::normal(Suit::Heart, SuitValue::_5));
+    knowledge.observe(&turn);
+    turn.put(1, Card::normal(Suit::Club, SuitValue::King));
+    knowledge.observe(&turn);
+    let pool = [
+        Card::normal(Suit::Heart, SuitValue::King),
+        Card::Trump(Trump::_2),
+        Card::normal(Suit::Spade, SuitValue::_1),
+    ];
+    let mut rng = rand::SeedableRng::seed_from_u64(0);
+    let dealt = deal_hidden(
+        &pool,
+        &[2, 1],
+        &[knowledge.voids(0), knowledge.voids(1)],
+        &mut rng,
+    );
+    assert_eq!(
+        dealt.get(1),
+        Some(&Deck::new(vec![Card::normal(Suit::Spade, SuitValue::_1)]))
+    );
+
+    // no card of a shown dog is dealt to a defender
+    let mut rng = rand::SeedableRng::seed_from_u64(7);
+    let mut deck = Deck::random(&mut rng);
+    let dog = deck.give(Mode::Four.dog_size());
+    let mut defender = PlayerInGame::new(Mode::Four, options, 1);
+    defender.extend_hand(&deck.give(Mode::Four.cards_per_player()));
+    defender.set_taker_index(0);
+    defender.set_team(Team::Defense);
+    defender.knowledge_mut().set_contract(Contract::Garde);
+    defender.knowledge_mut().show_dog(&dog);
+    for _ in 0..50 {
+        let simulation = determinize(&defender, &Turn::default(), &mut rng)?;
+        for seat in 1..Mode::Four.players() {
+            let hand = simulation.hand(seat)?;
+            assert!(dog.iter().all(|card| !hand.has(card)));
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,137 @@
+use derive_new::new;
+use rand::rngs::StdRng;
+
+use crate::card::Card;
+use crate::contract::Contract;
+use crate::errors::TarotErrorKind;
+use crate::family::Family;
+use crate::handle::Handle;
+use crate::heuristic_strategy::HeuristicStrategy;
+use crate::ismcts::{self, Budget};
+use crate::misere::Misere;
+use crate::mode::Mode;
+use crate::player::Player;
+use crate::player_in_game::PlayerInGame;
+use crate::strategy::PlayerStrategy;
+use crate::turn::Turn;
+
+/// Searches bids, discards and card play over sampled deals, other decisions follow the heuristic bot
+#[derive(new, Debug, Copy, Clone)]
+pub struct IsmctsStrategy {
+    // RULE: in attack mode, bots always pass and let the human take
+    attack: bool,
+    budget: Budget,
+}
+
+impl IsmctsStrategy {
+    fn heuristic(&self) -> HeuristicStrategy {
+        HeuristicStrategy::new(self.attack)
+    }
+    // only French deals are sampled, without face down cards, the heuristic decides elsewhere
+    fn searches(&self, in_game: &PlayerInGame) -> bool {
+        !self.budget.is_empty()
+            && *in_game.mode() != Mode::Two
+            && in_game.options().family == Family::French
+    }
+}
+
+impl PlayerStrategy for IsmctsStrategy {
+    fn bid(
+        &self,
+        player: &Player,
+        in_game: &PlayerInGame,
+        contracts: &[Contract],
+        rng: &mut StdRng,
+    ) -> Result<Option<Contract>, TarotErrorKind> {
+        if self.attack {
+            return Ok(None);
+        }
+        if !self.searches(in_game) {
+            return self.heuristic().bid(player, in_game, contracts, rng);
+        }
+        ismcts::bid(in_game, contracts, self.budget, rng)
+    }
+    fn slam(
+        &self,
+        player: &Player,
+        in_game: &PlayerInGame,
+        rng: &mut StdRng,
+    ) -> Result<bool, TarotErrorKind> {
+        self.heuristic().slam(player, in_game, rng)
+    }
+    fn misere(
+        &self,
+        player: &Player,
+        in_game: &PlayerInGame,
+        misere: Misere,
+        rng: &mut StdRng,
+    ) -> Result<bool, TarotErrorKind> {
+        self.heuristic().misere(player, in_game, misere, rng)
+    }
+    fn handle(
+        &self,
+        player: &Player,
+        in_game: &PlayerInGame,
+        trumps: &[Card],
+        handles: &[Handle],
+        rng: &mut StdRng,
+    ) -> Result<Handle, TarotErrorKind> {
+        self.heuristic()
+            .handle(player, in_game, trumps, handles, rng)
+    }
+    fn hide_trump(
+        &self,
+        player: &Player,
+        in_game: &PlayerInGame,
+        trumps: &[Card],
+        limit: usize,
+        rng: &mut StdRng,
+    ) -> Result<usize, TarotErrorKind> {
+        self.heuristic()
+            .hide_trump(player, in_game, trumps, limit, rng)
+    }
+    fn call(
+        &self,
+        player: &Player,
+        in_game: &PlayerInGame,
+        choices: &[Card],
+        rng: &mut StdRng,
+    ) -> Result<Card, TarotErrorKind> {
+        self.heuristic().call(player, in_game, choices, rng)
+    }
+    fn discard(
+        &self,
+        player: &Player,
+        in_game: &PlayerInGame,
+        discardables: &[usize],
+        rng: &mut StdRng,
+    ) -> Result<usize, TarotErrorKind> {
+        if self.searches(in_game)
+            && let Some(index) = ismcts::discard(in_game, discardables, self.budget, rng)?
+        {
+            return Ok(index);
+        }
+        self.heuristic().discard(player, in_game, discardables, rng)
+    }
+    fn play(
+        &self,
+        player: &Player,
+        in_game: &PlayerInGame,
+        turn: &Turn,
+        choices: &[usize],
+        rng: &mut StdRng,
+    ) -> Result<usize, TarotErrorKind> {
+        if let [only_choice] = choices {
+            return Ok(*only_choice);
+        }
+        if self.searches(in_game)
+            && let Some(card) = ismcts::play(in_game, turn, self.budget, rng)?
+            && let Some(index) = choices
+                .iter()
+                .find(|index| in_game.hand().get(**index) == Some(&card))
+        {
+            return Ok(*index);
+        }
+        self.heuristic().play(player, in_game, turn, choices, rng)
+    }
+}
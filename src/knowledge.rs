@@ -0,0 +1,270 @@
+use strum::IntoEnumIterator;
+
+use crate::auction::Auction;
+use crate::card::Card;
+use crate::contract::Contract;
+use crate::deck::Deck;
+use crate::handle::ShownHandle;
+use crate::misere::ShownMisere;
+use crate::suit::Suit;
+use crate::suit_value::SuitValue;
+use crate::trump::Trump;
+use crate::turn::Turn;
+
+/// Cards a holder cannot have, deduced from the way it followed
+#[derive(Default, Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Voids {
+    suits: Vec<Suit>,
+    trumps: bool,
+    kings: bool,
+    ceiling: Option<Trump>,
+}
+
+impl Voids {
+    // RULE: a discard holds neither kings nor trumps
+    #[must_use]
+    pub fn discard() -> Self {
+        Self {
+            trumps: true,
+            kings: true,
+            ..Self::default()
+        }
+    }
+    #[must_use]
+    pub fn is_void(&self, suit: Suit) -> bool {
+        self.suits.contains(&suit)
+    }
+    #[must_use]
+    pub const fn has_no_trumps(&self) -> bool {
+        self.trumps
+    }
+    // highest trump the holder could still have is below this one
+    #[must_use]
+    pub const fn ceiling(&self) -> Option<Trump> {
+        self.ceiling
+    }
+    #[must_use]
+    pub fn allows(&self, card: Card) -> bool {
+        match card {
+            Card::Trump(Trump::Fool) => true,
+            Card::Trump(trump) => !self.trumps && self.ceiling.is_none_or(|top| trump < top),
+            Card::Normal(normal) => {
+                let king = normal.value() == &SuitValue::King;
+                !(self.is_void(*normal.suit()) || (self.kings && king))
+            }
+        }
+    }
+    fn void(&mut self, suit: Suit) {
+        if !self.is_void(suit) {
+            self.suits.push(suit);
+        }
+    }
+    fn under(&mut self, master: Card) {
+        if let Card::Trump(master) = master {
+            self.ceiling = Some(self.ceiling.map_or(master, |top| top.min(master)));
+        }
+    }
+    // RULE: a player follows the called color, else trumps, else over-trumps when possible
+    fn observe(&mut self, called: Card, master: Card, played: Card) {
+        match (called, played) {
+            (_, Card::Trump(Trump::Fool)) => {}
+            (Card::Normal(called), Card::Normal(played)) => {
+                if called.suit() != played.suit() {
+                    self.void(*called.suit());
+                    self.trumps = true;
+                }
+            }
+            (Card::Normal(called), Card::Trump(_)) => {
+                self.void(*called.suit());
+                if master.master(played) {
+                    self.under(master);
+                }
+            }
+            (Card::Trump(_), Card::Normal(_)) => self.trumps = true,
+            (Card::Trump(_), Card::Trump(_)) => {
+                if master.master(played) {
+                    self.under(master);
+                }
+            }
+        }
+    }
+}
+
+/// What a seat knows of the deal : public events and everything deduced from the cards played
+#[derive(Default, Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Knowledge {
+    ally: Option<usize>,
+    auction: Auction,
+    contract: Option<Contract>,
+    dog: Option<Deck>,
+    handles: Vec<ShownHandle>,
+    miseres: Vec<ShownMisere>,
+    plays: Vec<(usize, Card)>,
+    turns: Vec<Turn>,
+    voids: Vec<Voids>,
+}
+
+impl Knowledge {
+    pub fn set_auction(&mut self, auction: &Auction) {
+        self.auction = auction.clone();
+    }
+    pub const fn set_contract(&mut self, contract: Contract) {
+        self.contract = Some(contract);
+    }
+    pub fn show_dog(&mut self, dog: &Deck) {
+        self.dog = Some(dog.clone());
+    }
+    // the called card was played or found in the dog, the taker is his own ally when alone
+    pub const fn reveal_ally(&mut self, player_index: usize) {
+        self.ally = Some(player_index);
+    }
+    pub fn show_handle(&mut self, shown: &ShownHandle) {
+        self.handles.push(shown.clone());
+    }
+    pub fn show_misere(&mut self, shown: &ShownMisere) {
+        self.miseres.push(shown.clone());
+    }
+    // the last card put on the turn, and what it tells about its player
+    pub fn observe(&mut self, turn: &Turn) {
+        let mut before = Turn::default();
+        let mut last = None;
+        for (player_index, card) in turn.plays() {
+            if let Some((previous_index, previous)) = last {
+                before.put(previous_index, previous);
+            }
+            last = Some((player_index, card));
+        }
+        let Some((player_index, card)) = last else {
+            return;
+        };
+        self.plays.push((player_index, card));
+        if let (Some(called), Some(master)) =
+            (before.called().copied(), before.master_card().copied())
+        {
+            if self.voids.len() <= player_index {
+                self.voids.resize(player_index + 1, Voids::default());
+            }
+            if let Some(voids) = self.voids.get_mut(player_index) {
+                voids.observe(called, master, card);
+            }
+        }
+    }
+    pub fn record_turn(&mut self, turn: &Turn) {
+        self.turns.push(turn.clone());
+    }
+    #[must_use]
+    pub const fn ally(&self) -> Option<usize> {
+        self.ally
+    }
+    #[must_use]
+    pub const fn auction(&self) -> &Auction {
+        &self.auction
+    }
+    #[must_use]
+    pub const fn contract(&self) -> Option<Contract> {
+        self.contract
+    }
+    #[must_use]
+    pub const fn dog(&self) -> Option<&Deck> {
+        self.dog.as_ref()
+    }
+    #[must_use]
+    pub fn handles(&self) -> &[ShownHandle] {
+        &self.handles
+    }
+    #[must_use]
+    pub fn miseres(&self) -> &[ShownMisere] {
+        &self.miseres
+    }
+    #[must_use]
+    pub fn turns(&self) -> &[Turn] {
+        &self.turns
+    }
+    pub fn played(&self) -> impl Iterator<Item = (usize, Card)> + '_ {
+        self.plays.iter().copied()
+    }
+    #[must_use]
+    pub fn is_played(&self, card: Card) -> bool {
+        self.plays.iter().any(|(_, played)| *played == card)
+    }
+    #[must_use]
+    pub fn played_by(&self, card: Card) -> Option<usize> {
+        self.plays
+            .iter()
+            .find(|(_, played)| *played == card)
+            .map(|(player_index, _)| *player_index)
+    }
+    #[must_use]
+    pub fn voids(&self, player_index: usize) -> Voids {
+        self.voids.get(player_index).cloned().unwrap_or_default()
+    }
+    // trumps, the fool apart, neither played nor in the given hand
+    #[must_use]
+    pub fn remaining_trumps(&self, hand: &Deck) -> usize {
+        Trump::iter()
+            .filter(|trump| *trump != Trump::Fool)
+            .map(Card::Trump)
+            .filter(|card| !self.is_played(*card) && !hand.has(card))
+            .count()
+    }
+    // trumps above this one neither played nor in the given hand
+    #[must_use]
+    pub fn higher_trumps(&self, trump: Trump, hand: &Deck) -> usize {
+        Trump::iter()
+            .filter(|higher| *higher > trump)
+            .map(Card::Trump)
+            .filter(|card| !self.is_played(*card) && !hand.has(card))
+            .count()
+    }
+    // petit, 21 and excuse which are not played yet
+    #[must_use]
+    pub fn oudlers_out(&self) -> Vec<Card> {
+        [Trump::Petit, Trump::_21, Trump::Fool]
+            .into_iter()
+            .map(Card::Trump)
+            .filter(|card| !self.is_played(*card))
+            .collect()
+    }
+}
+
+#[test]
+fn knowledge_tests() {
+    let mut knowledge = Knowledge::default();
+    let mut turn = Turn::default();
+    for (player_index, card) in [
+        (0, Card::normal(Suit::Heart, SuitValue::_5)),
+        (1, Card::Trump(Trump::_10)),
+        (2, Card::Trump(Trump::_4)),
+        (3, Card::Trump(Trump::Fool)),
+    ] {
+        turn.put(player_index, card);
+        knowledge.observe(&turn);
+    }
+    knowledge.record_turn(&turn);
+
+    assert!(
+        knowledge
+            .voids(0)
+            .allows(Card::normal(Suit::Heart, SuitValue::King))
+    );
+    assert!(knowledge.voids(1).is_void(Suit::Heart));
+    assert!(!knowledge.voids(1).has_no_trumps());
+    assert_eq!(knowledge.voids(2).ceiling(), Some(Trump::_10));
+    assert!(!knowledge.voids(2).allows(Card::Trump(Trump::_12)));
+    assert!(
+        knowledge
+            .voids(3)
+            .allows(Card::normal(Suit::Club, SuitValue::_1))
+    );
+    assert_eq!(knowledge.played_by(Card::Trump(Trump::_10)), Some(1));
+
+    let hand = Deck::new(vec![Card::Trump(Trump::_21), Card::Trump(Trump::_20)]);
+    assert_eq!(knowledge.remaining_trumps(&hand), 21 - 2 - 2);
+    assert_eq!(knowledge.higher_trumps(Trump::_18, &hand), 1);
+    assert_eq!(
+        knowledge.oudlers_out(),
+        vec![Card::Trump(Trump::Petit), Card::Trump(Trump::_21)]
+    );
+}
@@ -0,0 +1,567 @@
+use array_init::{array_init, try_array_init};
+use rand::SeedableRng;
+use rand::rngs::StdRng;
+use std::fmt;
+use strum::IntoEnumIterator;
+
+use crate::card::Card;
+use crate::constants::TAROCK_CARDS;
+use crate::deck::Deck;
+use crate::errors::TarotErrorKind;
+use crate::family::Family;
+use crate::mode::Mode;
+use crate::options::Options;
+use crate::player::Player;
+use crate::player_in_game::PlayerInGame;
+use crate::role::Role;
+use crate::strategy;
+use crate::suit::Suit;
+use crate::suit_value::SuitValue;
+use crate::talon::Talon;
+use crate::tarock_contract::TarockContract;
+use crate::tarock_scoring;
+use crate::tarock_strategy::{self, TarockStrategy};
+use crate::team::Team;
+use crate::trump::Trump;
+use crate::turn::Turn;
+
+const PLAYERS: usize = Mode::Four.players();
+const TALON_SIZE: usize = 6;
+// RULE: the talon is dealt first, then each player receives two packets of six cards
+const PACKET: usize = 6;
+
+// strength of a card in its suit, the sküs is the highest trump and red pips are ranked backwards
+fn rank(card: Card) -> usize {
+    match card {
+        Card::Trump(Trump::Fool) => Trump::_21 as usize + 1,
+        Card::Trump(trump) => trump as usize,
+        Card::Normal(normal) => match (normal.suit(), normal.value()) {
+            (Suit::Heart | Suit::Diamond, value) if *value <= SuitValue::_4 => {
+                SuitValue::_4 as usize + 1 - *value as usize
+            }
+            (_, value) => *value as usize,
+        },
+    }
+}
+
+fn same_suit(card: Card, led: Card) -> bool {
+    match (card, led) {
+        (Card::Trump(_), Card::Trump(_)) => true,
+        (Card::Normal(card), Card::Normal(led)) => card.suit() == led.suit(),
+        (Card::Trump(_), Card::Normal(_)) | (Card::Normal(_), Card::Trump(_)) => false,
+    }
+}
+
+// RULE: a trump beats any suit card, otherwise only a higher card of the same suit wins
+fn beats(card: Card, master: Card) -> bool {
+    match (card, master) {
+        (Card::Trump(_), Card::Normal(_)) => true,
+        (Card::Normal(_), Card::Trump(_)) => false,
+        _ => same_suit(card, master) && rank(card) > rank(master),
+    }
+}
+
+// index in the turn of the card winning it so far
+#[must_use]
+pub fn master_index(turn: &Turn) -> Option<usize> {
+    let mut master: Option<(usize, Card)> = None;
+    for (index, card) in turn.cards().iter().enumerate() {
+        if master.is_none_or(|(_, best)| beats(*card, best)) {
+            master = Some((index, *card));
+        }
+    }
+    master.map(|(index, _)| index)
+}
+
+// RULE: follow the led suit, else trump, else play anything, there is no need to overtrump
+#[must_use]
+pub fn choices(hand: &Deck, turn: &Turn) -> Vec<usize> {
+    let among = |keep: &dyn Fn(Card) -> bool| -> Vec<usize> {
+        hand.iter()
+            .enumerate()
+            .filter(|(_, card)| keep(**card))
+            .map(|(index, _)| index)
+            .collect()
+    };
+    let Some(led) = turn.cards().first().copied() else {
+        return among(&|_| true);
+    };
+    let follow = among(&|card| same_suit(card, led));
+    if !follow.is_empty() {
+        return follow;
+    }
+    let trumps = among(&Card::is_trump);
+    if !trumps.is_empty() {
+        return trumps;
+    }
+    among(&|_| true)
+}
+
+// RULE: neither kings nor honours can be laid away, any other card can, trumps included
+#[must_use]
+pub fn discardables(hand: &Deck) -> Vec<usize> {
+    hand.iter()
+        .enumerate()
+        .filter(|(_, card)| match card {
+            Card::Trump(trump) => !trump.is_oudler(),
+            Card::Normal(normal) => *normal.value() != SuitValue::King,
+        })
+        .map(|(index, _)| index)
+        .collect()
+}
+
+// trumps and honours make the games, a hand without any high card may lose every trick
+#[must_use]
+pub fn contract_for(hand: &Deck) -> Option<TarockContract> {
+    let trumps = hand.trumps().len();
+    let honours = hand.count_oudlers();
+    let kings = hand.count_tete(SuitValue::King);
+    let high = hand
+        .iter()
+        .filter(|card| card.is_trump() && rank(**card) > Trump::_15 as usize)
+        .count();
+    if trumps >= 10 && honours >= 2 {
+        Some(TarockContract::Solo)
+    } else if trumps >= 8 && honours + kings >= 3 {
+        Some(TarockContract::Dreier)
+    } else if trumps >= 5 || (trumps >= 4 && honours + kings >= 2) {
+        Some(TarockContract::Rufer)
+    } else if trumps <= 2 && high + honours + kings == 0 {
+        Some(TarockContract::Bettler)
+    } else {
+        None
+    }
+}
+
+// half of the talon bringing the most points, a trump counting as one more point
+#[must_use]
+pub fn best_half(halves: &[Deck]) -> usize {
+    halves
+        .iter()
+        .enumerate()
+        .max_by_key(|(_, half)| {
+            tarock_scoring::thirds(half) + 3 * i32::try_from(half.trumps().len()).unwrap_or(0)
+        })
+        .map_or(0, |(index, _)| index)
+}
+
+/// A game of Königrufen : four players, 54 cards and a talon of six cards
+#[derive(Debug)]
+pub struct Koenigrufen {
+    options: Options,
+    players: [Player; PLAYERS],
+    // the bids and talon choices of each player, seated like the players
+    tarock: [Box<dyn TarockStrategy>; PLAYERS],
+    deck: Option<Deck>,
+    seed: u64,
+    rng: StdRng,
+}
+
+pub fn launch(mode: Mode, options: Options, deals: u64, seed: u64) -> Result<(), TarotErrorKind> {
+    if !Family::Koenigrufen.supports(mode) {
+        return Err(TarotErrorKind::InvalidPlayers(mode.players().to_string()));
+    }
+    Koenigrufen::new(options, seed)?.start(deals)
+}
+
+impl fmt::Display for Koenigrufen {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "Seed : {}", self.seed)?;
+        writeln!(f, "Players : ")?;
+        for player in &self.players {
+            writeln!(f, "\t{player}")?;
+        }
+        Ok(())
+    }
+}
+
+impl Koenigrufen {
+    pub fn new(options: Options, seed: u64) -> Result<Self, TarotErrorKind> {
+        let names = try_array_init(|i| -> Result<&str, TarotErrorKind> {
+            Mode::Four.player_name(options.direction.seat(i, PLAYERS))
+        })?;
+        let players = names.map(|name| {
+            let strategy = strategy::from_options(options, name == "South");
+            Player::new(name.to_string(), options, strategy)
+        });
+        let tarock = names.map(|name| tarock_strategy::from_options(options, name == "South"));
+        Ok(Self {
+            options,
+            players,
+            tarock,
+            deck: None,
+            seed,
+            rng: StdRng::seed_from_u64(seed),
+        })
+    }
+    #[must_use]
+    pub const fn players(&self) -> &[Player; PLAYERS] {
+        &self.players
+    }
+    pub fn is_consistent(&self) -> Result<(), TarotErrorKind> {
+        let sum: i32 = self.players.iter().map(Player::score).sum();
+        if sum != 0 {
+            eprintln!("Inconsistent points sum : {sum}");
+            return Err(TarotErrorKind::InvalidScores(sum.to_string()));
+        }
+        Ok(())
+    }
+    pub fn start(&mut self, deals: u64) -> Result<(), TarotErrorKind> {
+        for left in (1..=deals).rev() {
+            if !self.options.quiet {
+                println!("Deals left : {left}");
+            }
+            self.play_deal()?;
+            // RULE: players are rotated after each deal, so the dealer always sits last
+            self.players.rotate_left(1);
+            self.tarock.rotate_left(1);
+        }
+        if !self.options.quiet {
+            println!("GAME ENDED");
+            println!("{self}");
+        }
+        Ok(())
+    }
+    fn play_deal(&mut self) -> Result<(), TarotErrorKind> {
+        let quiet = self.options.quiet;
+        let mut deck =
+            self.options
+                .shuffle
+                .apply_on(self.deck.take(), Deck::tarock(), &mut self.rng);
+        if deck.len() != TAROCK_CARDS {
+            return Err(TarotErrorKind::InvalidDeck(deck));
+        }
+        let mut talon = Talon::new(&deck.give(TALON_SIZE))?;
+        let options = self.options;
+        let mut in_games: [PlayerInGame; PLAYERS] =
+            array_init(|seat| PlayerInGame::new(Mode::Four, options, seat));
+        for packet in 0..2 * PLAYERS {
+            in_games
+                .get_mut(packet % PLAYERS)
+                .ok_or(TarotErrorKind::NoPlayer(packet % PLAYERS))?
+                .extend_hand(&deck.give(PACKET));
+        }
+
+        let (declarer, contract) = self.auction(&in_games)?;
+        if !quiet {
+            let name = self.players.get(declarer).map_or("", Player::name);
+            println!("Player {name} plays a {contract}");
+        }
+        let callee = if contract.calls() {
+            self.call(declarer, &in_games)?
+        } else {
+            None
+        };
+        let partner = callee
+            .and_then(|callee| in_games.iter().position(|in_game| in_game.has(&callee)))
+            .filter(|seat| *seat != declarer);
+        let attack = |seat: usize| seat == declarer || Some(seat) == partner;
+        // RULE: with the called king in the talon, the declarer plays alone
+        let alone = callee.is_some_and(|callee| talon.cards().has(&callee));
+        if alone && !quiet {
+            println!("Called card is in the talon, the declarer plays alone");
+        }
+        for (seat, in_game) in in_games.iter_mut().enumerate() {
+            in_game.set_taker_index(declarer);
+            in_game.set_callee(callee);
+            in_game.knowledge_mut().show_dog(&talon.cards());
+            if alone {
+                in_game.knowledge_mut().reveal_ally(declarer);
+            }
+            if seat == declarer {
+                in_game.set_team(Team::Attack);
+                in_game.set_role(Role::Taker);
+            } else if attack(seat) {
+                in_game.set_team(Team::Attack);
+                in_game.set_role(Role::Ally);
+            } else {
+                in_game.set_team(Team::Defense);
+                in_game.set_role(Role::Defenser);
+            }
+        }
+
+        let mut attack_pile = if contract.uses_talon() {
+            self.exchange(declarer, &mut talon, &mut in_games)?
+        } else {
+            Deck::default()
+        };
+        // RULE: the cards left in the talon go to the defense
+        let mut defense_pile = talon.cards();
+
+        let tricks = self.tricks(&mut in_games)?;
+        let mut attack_tricks = 0;
+        for (winner, cards) in &tricks {
+            if attack(*winner) {
+                attack_tricks += 1;
+                attack_pile.extend(cards);
+            } else {
+                defense_pile.extend(cards);
+            }
+        }
+
+        let thirds = tarock_scoring::thirds(&attack_pile);
+        let won = tarock_scoring::won(contract, thirds, attack_tricks);
+        let valat =
+            !contract.is_negative() && (attack_tricks == 0 || attack_tricks == tricks.len());
+        let points = tarock_scoring::points(contract, won, valat);
+        if !quiet {
+            println!(
+                "Declarers made {} points in {attack_tricks} tricks",
+                tarock_scoring::repr(thirds)
+            );
+            if valat {
+                println!("Valat !");
+            }
+            println!("{contract} won : {won}, points : {points}");
+        }
+        let declarers = if partner.is_some() { 2 } else { 1 };
+        let defenders = i32::try_from(PLAYERS).unwrap_or(0) - declarers;
+        let (declarer_score, defender_score) =
+            tarock_scoring::payments(points, declarers, defenders);
+        for (seat, player) in self.players.iter_mut().enumerate() {
+            if attack(seat) {
+                player.add_score(declarer_score);
+            } else {
+                player.add_score(defender_score);
+            }
+        }
+
+        attack_pile.extend(&defense_pile);
+        if attack_pile.len() != TAROCK_CARDS {
+            return Err(TarotErrorKind::InvalidDeck(attack_pile));
+        }
+        self.deck = Some(attack_pile);
+        self.is_consistent()
+    }
+    // RULE: each player in turn names a higher game or passes, the forehand plays a rufer when everybody passed
+    fn auction(
+        &mut self,
+        in_games: &[PlayerInGame; PLAYERS],
+    ) -> Result<(usize, TarockContract), TarotErrorKind> {
+        let mut best: Option<(usize, TarockContract)> = None;
+        for (seat, ((player, tarock), in_game)) in self
+            .players
+            .iter()
+            .zip(&self.tarock)
+            .zip(in_games)
+            .enumerate()
+        {
+            let available: Vec<TarockContract> = TarockContract::iter()
+                .filter(|contract| best.is_none_or(|(_, highest)| *contract > highest))
+                .collect();
+            if available.is_empty() {
+                break;
+            }
+            let bid = tarock.tarock_bid(player, in_game, &available, &mut self.rng)?;
+            match bid {
+                Some(contract) if !available.contains(&contract) => {
+                    return Err(TarotErrorKind::IllegalChoice(format!(
+                        "game {contract} is not available"
+                    )));
+                }
+                Some(contract) => {
+                    if !self.options.quiet {
+                        println!("Player {} announces {contract}", player.name());
+                    }
+                    best = Some((seat, contract));
+                }
+                None => {
+                    if !self.options.quiet {
+                        println!("Player {} passes", player.name());
+                    }
+                }
+            }
+        }
+        Ok(best.unwrap_or((0, TarockContract::Rufer)))
+    }
+    // RULE: the declarer calls a king, or a queen when holding the four kings
+    fn call(
+        &mut self,
+        declarer: usize,
+        in_games: &[PlayerInGame; PLAYERS],
+    ) -> Result<Option<Card>, TarotErrorKind> {
+        let (Some(player), Some(in_game)) = (self.players.get(declarer), in_games.get(declarer))
+        else {
+            return Err(TarotErrorKind::NoTaker(declarer));
+        };
+        let value = if in_game.hand().count_tete(SuitValue::King) == 4 {
+            SuitValue::Queen
+        } else {
+            SuitValue::King
+        };
+        let choices: Vec<Card> = Suit::iter().map(|suit| Card::normal(suit, value)).collect();
+        let callee = player
+            .strategy()
+            .call(player, in_game, &choices, &mut self.rng)?;
+        if !choices.contains(&callee) {
+            return Err(TarotErrorKind::IllegalChoice(format!(
+                "card {callee} cannot be called"
+            )));
+        }
+        if !self.options.quiet {
+            println!("Called card for partner is {callee}");
+        }
+        Ok(Some(callee))
+    }
+    // RULE: the declarer takes a half of the talon and lays away as many cards, neither kings nor honours
+    fn exchange(
+        &mut self,
+        declarer: usize,
+        talon: &mut Talon,
+        in_games: &mut [PlayerInGame; PLAYERS],
+    ) -> Result<Deck, TarotErrorKind> {
+        let (Some(player), Some(tarock), Some(in_game)) = (
+            self.players.get(declarer),
+            self.tarock.get(declarer),
+            in_games.get_mut(declarer),
+        ) else {
+            return Err(TarotErrorKind::NoTaker(declarer));
+        };
+        if !self.options.quiet {
+            println!("Talon :\n{talon}");
+        }
+        let index = tarock.talon(player, in_game, talon.halves(), &mut self.rng)?;
+        let half = talon.take(index)?;
+        in_game.extend_hand(&half);
+        let mut laid = Deck::default();
+        while laid.len() < half.len() {
+            let discardables = discardables(in_game.hand());
+            let index = player
+                .strategy()
+                .discard(player, in_game, &discardables, &mut self.rng)?;
+            if !discardables.contains(&index) {
+                return Err(TarotErrorKind::IllegalChoice(format!(
+                    "card at index {index} cannot be laid away"
+                )));
+            }
+            let card = *in_game
+                .hand()
+                .get(index)
+                .ok_or(TarotErrorKind::NoCard(index))?;
+            laid.extend(&Deck::new(
+                in_game.take_from_hand(&card).into_iter().collect(),
+            ));
+        }
+        if !self.options.quiet {
+            println!("Laid away : {laid}");
+        }
+        Ok(laid)
+    }
+    // RULE: the forehand leads the first trick, then the winner of each trick leads the next one
+    fn tricks(
+        &mut self,
+        in_games: &mut [PlayerInGame; PLAYERS],
+    ) -> Result<Vec<(usize, Deck)>, TarotErrorKind> {
+        let quiet = self.options.quiet;
+        let mut leader = 0;
+        let mut tricks = Vec::new();
+        while in_games.iter().any(|in_game| !in_game.last_turn()) {
+            let mut turn = Turn::default();
+            for offset in 0..PLAYERS {
+                let seat = (leader + offset) % PLAYERS;
+                let (Some(player), Some(in_game)) =
+                    (self.players.get(seat), in_games.get_mut(seat))
+                else {
+                    return Err(TarotErrorKind::NoPlayer(seat));
+                };
+                let choices = choices(in_game.hand(), &turn);
+                if !quiet {
+                    println!("{} with hand : {}", player.name(), in_game.hand());
+                    println!("Must play one card, choices :");
+                    choices
+                        .iter()
+                        .filter_map(|index| in_game.hand().get(*index).map(|card| (index, card)))
+                        .for_each(|(index, card)| println!("\t{card: <4} : press {index}"));
+                }
+                let index =
+                    player
+                        .strategy()
+                        .play(player, in_game, &turn, &choices, &mut self.rng)?;
+                if !choices.contains(&index) {
+                    return Err(TarotErrorKind::IllegalChoice(format!(
+                        "card at index {index} cannot be played"
+                    )));
+                }
+                let card = *in_game
+                    .hand()
+                    .get(index)
+                    .ok_or(TarotErrorKind::NoCard(index))?;
+                in_game.take_from_hand(&card);
+                // RULE: the partner is known when the called card is played
+                let revealed = in_game.callee() == Some(card);
+                turn.put(seat, card);
+                turn.master_index = master_index(&turn);
+                if !quiet {
+                    println!("{turn}");
+                }
+                for in_game in in_games.iter_mut() {
+                    if revealed {
+                        in_game.knowledge_mut().reveal_ally(seat);
+                    }
+                }
+            }
+            let winner = turn
+                .master_player()
+                .ok_or(TarotErrorKind::NoMaster(leader))?;
+            for in_game in in_games.iter_mut() {
+                in_game.knowledge_mut().record_turn(&turn);
+            }
+            if !quiet {
+                let name = self.players.get(winner).map_or("", Player::name);
+                println!("Player {name} has win turn");
+            }
+            leader = winner;
+            tricks.push((winner, turn.take_cards()));
+        }
+        Ok(tricks)
+    }
+}
+
+#[test]
+fn koenigrufen_tests() -> Result<(), TarotErrorKind> {
+    let heart = |value| Card::normal(Suit::Heart, value);
+    let mut turn = Turn::default();
+    turn.put(0, heart(SuitValue::_4));
+    turn.put(1, heart(SuitValue::_1));
+    assert_eq!(master_index(&turn), Some(1));
+    turn.put(2, Card::Trump(Trump::_21));
+    turn.put(3, Card::Trump(Trump::Fool));
+    assert_eq!(master_index(&turn), Some(3));
+
+    let mut led = Turn::default();
+    led.put(0, heart(SuitValue::King));
+    let hand = Deck::new(vec![
+        Card::Trump(Trump::_2),
+        Card::normal(Suit::Club, SuitValue::_7),
+    ]);
+    assert_eq!(choices(&hand, &led), vec![0]);
+    assert_eq!(choices(&hand, &Turn::default()), vec![0, 1]);
+
+    let hand = Deck::new(vec![
+        Card::Trump(Trump::Fool),
+        heart(SuitValue::King),
+        Card::Trump(Trump::_12),
+        Card::Trump(Trump::Petit),
+        heart(SuitValue::Queen),
+        Card::Trump(Trump::_21),
+    ]);
+    assert_eq!(discardables(&hand), vec![2, 4]);
+
+    let options = Options {
+        random: true,
+        test: true,
+        auto: true,
+        quiet: true,
+        family: Family::Koenigrufen,
+        ..Options::default()
+    };
+    assert!(launch(Mode::Five, options, 1, 0).is_err());
+    let mut first = Koenigrufen::new(options, 3)?;
+    first.start(4)?;
+    let mut second = Koenigrufen::new(options, 3)?;
+    second.start(4)?;
+    for (first_player, second_player) in first.players().iter().zip(second.players()) {
+        assert_eq!(first_player.score(), second_player.score());
+    }
+    Ok(())
+}
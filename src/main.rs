@@ -1,43 +1,87 @@
 use clap::Parser;
+use rand::Rng;
 use std::error;
 use std::num::NonZeroUsize;
 use std::str::FromStr;
 use std::thread;
+use std::time::Duration;
 use strum::IntoEnumIterator;
 
+pub mod auction;
+pub mod auto_strategy;
+pub mod call_planner;
 pub mod card;
+pub mod card_play;
 pub mod constants;
 pub mod contract;
 pub mod deck;
+pub mod direction;
+pub mod discard_planner;
+pub mod duplicate;
+#[cfg(feature = "serde")]
+pub mod encoding;
 pub mod errors;
+pub mod family;
 pub mod game;
 pub mod game_distributed;
 pub mod game_started;
+pub mod hand_evaluation;
 pub mod handle;
+pub mod handle_planner;
 pub mod helpers;
+pub mod heuristic_strategy;
+pub mod ismcts;
+pub mod ismcts_strategy;
+pub mod knowledge;
+pub mod koenigrufen;
+pub mod misere;
 pub mod mode;
 pub mod normal;
 pub mod options;
 pub mod player;
 pub mod player_in_game;
 pub mod points;
+pub mod random_strategy;
+pub mod record;
 pub mod role;
+pub mod rules;
+pub mod shuffle;
+pub mod simulation;
+pub mod slam;
+pub mod slam_planner;
+#[cfg(feature = "serde")]
+pub mod snapshot;
+pub mod stdin_strategy;
+pub mod strategy;
 pub mod suit;
 pub mod suit_value;
+pub mod table;
+pub mod talon;
+pub mod tarock_contract;
+pub mod tarock_scoring;
+pub mod tarock_strategy;
 pub mod team;
+pub mod tournament;
 pub mod traits;
 pub mod trump;
 pub mod turn;
 
-use crate::game::launch;
+use crate::auction::Redeal;
+use crate::direction::Direction;
+use crate::errors::TarotErrorKind;
+use crate::family::Family;
+use crate::ismcts::Budget;
 use crate::mode::Mode;
 use crate::options::Options;
+use crate::rules::RuleSet;
+use crate::shuffle::Shuffle;
+use crate::strategy::Bot;
 
 #[derive(Parser, Debug)]
 #[clap(author, about, version)]
 struct Opts {
     /// Players mode
-    #[arg(value_parser = clap::builder::PossibleValuesParser::new(["3", "4", "5"]), default_value = "4")]
+    #[arg(value_parser = clap::builder::PossibleValuesParser::new(["2", "3", "4", "5", "6"]), default_value = "4")]
     players: String,
 
     /// Number of deals per game
@@ -52,6 +96,30 @@ struct Opts {
     #[arg(short = 'r', long = "random")]
     random: bool,
 
+    /// Strategy of bots
+    #[arg(short = 'b', long = "bot", value_parser = clap::builder::PossibleValuesParser::new(["random", "heuristic", "ismcts"]), default_value = "random")]
+    bot: String,
+
+    /// Iterations of the ismcts bot for each decision
+    #[arg(long = "iterations", default_value_t = 200)]
+    iterations: usize,
+
+    /// Thinking time limit of the ismcts bot for each decision, in milliseconds
+    #[arg(long = "think-time")]
+    think_time: Option<u64>,
+
+    /// Direction of the deal and the play around the table
+    #[arg(long = "direction", value_parser = clap::builder::PossibleValuesParser::new(["counterclockwise", "clockwise"]), default_value = "counterclockwise")]
+    direction: String,
+
+    /// Cards of a deal are gathered and shuffled with this model for the next one
+    #[arg(long = "shuffle", value_parser = clap::builder::PossibleValuesParser::new(["random", "none", "cut", "riffle", "overhand"]), default_value = "random")]
+    shuffle: String,
+
+    /// Who deals again when everyone passed
+    #[arg(long = "redeal", value_parser = clap::builder::PossibleValuesParser::new(["next", "same"]), default_value = "next")]
+    redeal: String,
+
     /// Auto playing mode when possible
     #[arg(short = 'a', long = "auto")]
     auto: bool,
@@ -68,6 +136,38 @@ struct Opts {
     #[arg(long = "no-slam")]
     no_slam: bool,
 
+    /// Rule set : fft, house or the path of a TOML file
+    #[arg(long = "rules", default_value = "fft")]
+    rules: String,
+
+    /// Family of the game, Königrufen is played by four with 54 cards
+    #[arg(long = "family", value_parser = clap::builder::PossibleValuesParser::new(["french", "koenigrufen"]), default_value = "french")]
+    family: String,
+
+    /// Duplicate session, each deal is replayed under every seat permutation
+    #[arg(long = "duplicate")]
+    duplicate: bool,
+
+    /// Club tournament between this number of players, bots fill the empty seats
+    #[arg(long = "tournament", conflicts_with = "duplicate")]
+    tournament: Option<usize>,
+
+    /// Rounds of a tournament, players change tables between rounds
+    #[arg(long = "rounds", default_value_t = 3)]
+    rounds: usize,
+
+    /// Allow misère announcements, a house rule
+    #[arg(long = "misere")]
+    misere: bool,
+
+    /// Print a JSON snapshot of each deal before every trick and at its end, needs the serde feature
+    #[arg(long = "json")]
+    json: bool,
+
+    /// Seed for dealing and random decisions, replays a single game in test mode
+    #[arg(short = 's', long = "seed")]
+    seed: Option<u64>,
+
     /// Concurrency in test mode, default is number of cpu on this machine
     #[arg(short, default_value_t = thread::available_parallelism().unwrap())]
     concurrency: NonZeroUsize,
@@ -75,6 +175,8 @@ struct Opts {
 
 fn main() -> Result<(), Box<dyn error::Error>> {
     let opt = Opts::parse();
+    let mut rules = RuleSet::from_arg(&opt.rules)?;
+    rules.misere |= opt.misere;
     let options = Options {
         random: opt.random,
         auto: opt.auto,
@@ -82,16 +184,50 @@ fn main() -> Result<(), Box<dyn error::Error>> {
         no_slam: opt.no_slam,
         attack: opt.attack,
         test: opt.test,
+        bot: Bot::from_str(&opt.bot)?,
+        budget: Budget::new(opt.iterations, opt.think_time.map(Duration::from_millis)),
+        direction: Direction::from_str(&opt.direction)?,
+        shuffle: Shuffle::from_str(&opt.shuffle)?,
+        redeal: Redeal::from_str(&opt.redeal)?,
+        rules,
+        family: Family::from_str(&opt.family)?,
+        json: opt.json,
+    };
+    if opt.json && !cfg!(feature = "serde") {
+        return Err(
+            TarotErrorKind::Serialization("built without the serde feature".to_string()).into(),
+        );
+    }
+    let (duplicate, tournament, rounds) = (opt.duplicate, opt.tournament, opt.rounds);
+    let launch = move |mode, options, deals, seed| -> Result<(), TarotErrorKind> {
+        if duplicate {
+            return duplicate::launch(mode, options, deals, seed);
+        }
+        tournament.map_or_else(
+            || game::launch(mode, options, deals, seed),
+            |entrants| tournament::launch(mode, options, entrants, rounds, deals, seed),
+        )
     };
-    if opt.test {
+    if opt.test && opt.seed.is_none() {
         let mut children = vec![];
         for _ in 0..opt.concurrency.get() {
             children.push(thread::spawn(move || {
                 println!("Spawned thread {:?}", thread::current());
-                for mode in Mode::iter().cycle() {
-                    let result = launch(mode, options, opt.deals);
+                for mode in Mode::iter()
+                    .filter(|mode| options.family.supports(*mode))
+                    .filter(|mode| !duplicate || duplicate::supports(options, *mode))
+                    .filter(|mode| tournament.is_none() || tournament::supports(options, *mode))
+                    .cycle()
+                {
+                    let seed = rand::rng().random();
+                    let result = launch(mode, options, opt.deals, seed);
                     if let Err(e) = result {
-                        eprintln!("{:?} : {}", thread::current(), e);
+                        eprintln!(
+                            "{:?} : {} (players: {}, seed: {seed})",
+                            thread::current(),
+                            e,
+                            mode.players()
+                        );
                     }
                 }
             }));
@@ -101,9 +237,10 @@ fn main() -> Result<(), Box<dyn error::Error>> {
         }
     } else {
         let mode = Mode::from_str(&opt.players)?;
-        let result = launch(mode, options, opt.deals);
+        let seed = opt.seed.unwrap_or_else(|| rand::rng().random());
+        let result = launch(mode, options, opt.deals, seed);
         if let Err(e) = result {
-            eprintln!("{e}");
+            eprintln!("{e} (seed: {seed})");
         }
     }
     Ok(())
@@ -0,0 +1,81 @@
+use derive_new::new;
+use std::fmt;
+use strum::{Display, EnumIter, IntoEnumIterator};
+
+use crate::deck::Deck;
+
+/// House rule announcements of a hand without strength
+#[derive(Display, Eq, PartialEq, Debug, Copy, Clone, EnumIter)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Misere {
+    #[strum(serialize = "misère de tête")]
+    Tete,
+    #[strum(serialize = "misère d'atout")]
+    Atout,
+}
+
+impl Misere {
+    #[must_use]
+    pub fn holds(self, hand: &Deck) -> bool {
+        match self {
+            Self::Tete => hand.misere_tete(),
+            Self::Atout => hand.misere_atout(),
+        }
+    }
+    // misères the dealt hand allows to announce
+    #[must_use]
+    pub fn available(hand: &Deck) -> Vec<Self> {
+        Self::iter().filter(|misere| misere.holds(hand)).collect()
+    }
+}
+
+/// A misère announced before the auction, the whole hand is shown to prove it
+#[derive(new, Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ShownMisere {
+    seat: usize,
+    misere: Misere,
+    cards: Deck,
+}
+
+impl ShownMisere {
+    #[must_use]
+    pub const fn seat(&self) -> usize {
+        self.seat
+    }
+    #[must_use]
+    pub const fn misere(&self) -> Misere {
+        self.misere
+    }
+    #[must_use]
+    pub const fn cards(&self) -> &Deck {
+        &self.cards
+    }
+}
+
+impl fmt::Display for ShownMisere {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} of seat {} : {}", self.misere, self.seat, self.cards)
+    }
+}
+
+#[test]
+fn misere_tests() {
+    use crate::card::Card;
+    use crate::suit::Suit;
+    use crate::suit_value::SuitValue;
+    use crate::trump::Trump;
+
+    let low = Deck::new(vec![
+        Card::Trump(Trump::Fool),
+        Card::normal(Suit::Heart, SuitValue::_10),
+        Card::normal(Suit::Club, SuitValue::_1),
+    ]);
+    assert_eq!(Misere::available(&low), vec![Misere::Tete, Misere::Atout]);
+    let with_jack = Deck::new(vec![
+        Card::Trump(Trump::_2),
+        Card::normal(Suit::Spade, SuitValue::Jack),
+    ]);
+    assert!(Misere::available(&with_jack).is_empty());
+    assert_eq!(Misere::Atout.to_string(), "misère d'atout");
+}
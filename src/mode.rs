@@ -1,24 +1,35 @@
 use crate::errors::TarotErrorKind;
-use crate::handle::Handle;
-use ordered_float::OrderedFloat;
 use std::fmt;
 use std::str::FromStr;
 use strum::EnumIter;
 
 #[derive(Default, Debug, Copy, Clone, Eq, PartialEq, EnumIter)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Mode {
+    Two,
     Three,
     #[default]
     Four,
     Five,
+    Six,
 }
 
 impl fmt::Display for Mode {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match self {
+            Self::Two => write!(
+                f,
+                "{} players, 1 vs 1 (with face down cards on the table)",
+                self.players()
+            ),
             Self::Three => write!(f, "{} players, 1 vs 2 (easy)", self.players()),
             Self::Four => write!(f, "{} players, 1 vs 3 (standard)", self.players()),
             Self::Five => write!(f, "{} players, 2 vs 3 (call a king)", self.players()),
+            Self::Six => write!(
+                f,
+                "{} players, 2 vs 3 (call a king, the dealer sits out)",
+                self.players()
+            ),
         }
     }
 }
@@ -27,9 +38,11 @@ impl TryFrom<usize> for Mode {
     type Error = TarotErrorKind;
     fn try_from(value: usize) -> Result<Self, Self::Error> {
         match value {
+            2 => Ok(Self::Two),
             3 => Ok(Self::Three),
             4 => Ok(Self::Four),
             5 => Ok(Self::Five),
+            6 => Ok(Self::Six),
             _ => Err(Self::Error::InvalidPlayers(value.to_string())),
         }
     }
@@ -39,9 +52,11 @@ impl FromStr for Mode {
     type Err = TarotErrorKind;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         match s {
+            "2" | "two" => Ok(Self::Two),
             "3" | "three" => Ok(Self::Three),
             "4" | "four" => Ok(Self::Four),
             "5" | "five" => Ok(Self::Five),
+            "6" | "six" => Ok(Self::Six),
             _ => Err(Self::Err::InvalidPlayers(s.into())),
         }
     }
@@ -51,54 +66,77 @@ impl Mode {
     #[must_use]
     pub const fn players(self) -> usize {
         match self {
+            Self::Two => 2,
             Self::Three => 3,
             Self::Four => 4,
             Self::Five => 5,
+            Self::Six => 6,
         }
     }
     #[must_use]
-    pub const fn ratio(&self, with_ally: bool) -> OrderedFloat<f64> {
-        let ratio = match self {
-            Self::Three => 2.0,
-            Self::Four => 3.0,
+    pub const fn ratio(&self, with_ally: bool) -> i32 {
+        match self {
+            Self::Two => 1,
+            Self::Three => 2,
+            Self::Four => 3,
             Self::Five => {
                 if with_ally {
-                    2.0
+                    2
                 } else {
-                    4.0
+                    4
                 }
             }
-        };
-        OrderedFloat(ratio)
+            Self::Six => self.seated().ratio(with_ally),
+        }
     }
     #[must_use]
     pub const fn dog_size(&self) -> usize {
         match self {
             Self::Five => 3,
-            Self::Four | Self::Three => 6,
+            Self::Four | Self::Three | Self::Two => 6,
+            Self::Six => self.seated().dog_size(),
         }
     }
     #[must_use]
     pub const fn cards_per_turn(&self) -> usize {
         match self {
             Self::Three => 4,
-            Self::Five | Self::Four => 3,
+            Self::Two | Self::Five | Self::Four => 3,
+            Self::Six => self.seated().cards_per_turn(),
         }
     }
     #[must_use]
     pub const fn cards_per_player(&self) -> usize {
         match self {
+            // RULE: at two, a hand of 24 cards and 12 more laid on the table
+            Self::Two => 36,
             Self::Three => 24,
             Self::Four => 18,
             Self::Five => 15,
+            Self::Six => self.seated().cards_per_player(),
         }
     }
     #[must_use]
     pub const fn max_cards_for_taker(&self) -> usize {
         self.dog_size() + self.cards_per_player()
     }
+    #[must_use]
+    pub const fn table_size(&self) -> usize {
+        match self {
+            Self::Two => 12,
+            Self::Three | Self::Four | Self::Five => 0,
+            Self::Six => self.seated().table_size(),
+        }
+    }
     pub fn player_name(&self, index: usize) -> Result<&'static str, TarotErrorKind> {
         match self {
+            Self::Two => match index {
+                0 => Ok("North"),
+                1 => Ok("South"),
+                _ => Err(TarotErrorKind::InvalidCase(
+                    "Mode with 2 players does not support more than 2 default names".to_string(),
+                )),
+            },
             Self::Three => match index {
                 0 => Ok("East"),
                 1 => Ok("North"),
@@ -126,50 +164,25 @@ impl Mode {
                     "Mode with 5 players does not support more than 5 default names".to_string(),
                 )),
             },
-        }
-    }
-    #[must_use]
-    pub const fn handle(&self, count: usize) -> Option<Handle> {
-        match self {
-            Self::Three => match count {
-                0..=12 => None,
-                13..=14 => Some(Handle::Simple),
-                15..=17 => Some(Handle::Double),
-                _ => Some(Handle::Triple),
-            },
-            Self::Four => match count {
-                0..=9 => None,
-                10..=12 => Some(Handle::Simple),
-                13..=14 => Some(Handle::Double),
-                _ => Some(Handle::Triple),
-            },
-            Self::Five => match count {
-                0..=7 => None,
-                8..=9 => Some(Handle::Simple),
-                10..=12 => Some(Handle::Double),
-                _ => Some(Handle::Triple),
+            Self::Six => match index {
+                0 => Ok("East"),
+                1 => Ok("North"),
+                2 => Ok("South"),
+                3 => Ok("West"),
+                4 => Ok("Compass"),
+                5 => Ok("Center"),
+                _ => Err(TarotErrorKind::InvalidCase(
+                    "Mode with 6 players does not support more than 6 default names".to_string(),
+                )),
             },
         }
     }
+    // RULE: at six, the dealer sits out and the five others play a deal of five
     #[must_use]
-    pub const fn handle_limit(&self, handle: &Handle) -> usize {
-        match handle {
-            Handle::Refused => 0,
-            Handle::Simple => match self {
-                Self::Three => 13,
-                Self::Four => 10,
-                Self::Five => 8,
-            },
-            Handle::Double => match self {
-                Self::Three => 15,
-                Self::Four => 13,
-                Self::Five => 10,
-            },
-            Handle::Triple => match self {
-                Self::Three => 18,
-                Self::Four => 15,
-                Self::Five => 13,
-            },
+    pub const fn seated(self) -> Self {
+        match self {
+            Self::Six => Self::Five,
+            Self::Two | Self::Three | Self::Four | Self::Five => self,
         }
     }
 }
@@ -179,6 +192,14 @@ fn mode_tests() {
     let mode = Mode::default();
     println!("mode: {}", &mode);
 
+    let two = Mode::from_str("2");
+    assert_eq!(two, Ok(Mode::Two));
+    assert_eq!(Mode::Two.table_size() % 2, 0);
+    assert_eq!(
+        Mode::Two.players() * Mode::Two.cards_per_player() + Mode::Two.dog_size(),
+        crate::constants::MAX_CARDS
+    );
+
     let three = Mode::from_str("3");
     assert_eq!(three, Ok(Mode::Three));
 
@@ -187,4 +208,14 @@ fn mode_tests() {
 
     let five = Mode::from_str("5");
     assert_eq!(five, Ok(Mode::Five));
+
+    let six = Mode::from_str("6");
+    assert_eq!(six, Ok(Mode::Six));
+    assert_eq!(Mode::Six.seated(), Mode::Five);
+    assert_eq!(Mode::Six.ratio(true), Mode::Five.ratio(true));
+    assert_eq!(Mode::Six.ratio(false), Mode::Five.ratio(false));
+    assert_eq!(Mode::Six.dog_size(), Mode::Five.dog_size());
+    assert_eq!(Mode::Six.cards_per_turn(), Mode::Five.cards_per_turn());
+    assert_eq!(Mode::Six.cards_per_player(), Mode::Five.cards_per_player());
+    assert_eq!(Mode::Six.table_size(), Mode::Five.table_size());
 }
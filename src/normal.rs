@@ -1,11 +1,11 @@
-use crate::points::Points;
+use crate::errors::TarotErrorKind;
+use crate::points::{HalfPoints, Points};
 use crate::suit::Suit;
 use crate::suit_value::SuitValue;
-use crate::traits::{Discardable, Power, Representation};
+use crate::traits::{Code, Discardable, Power, Representation};
 use colored::{ColoredString, Colorize};
 use derive_new::new;
 use lazy_regex::regex;
-use ordered_float::OrderedFloat;
 use std::fmt;
 
 #[derive(new, Copy, Clone, Debug, Eq, PartialEq, Ord, PartialOrd)]
@@ -31,8 +31,26 @@ impl Normal {
     }
 }
 
+// value then suit, as 10H or KS
+impl Code for Normal {
+    fn code(&self) -> String {
+        format!("{}{}", self.value.code(), self.suit.code())
+    }
+    fn from_code(code: &str) -> Result<Self, TarotErrorKind> {
+        let (index, _) = code
+            .char_indices()
+            .last()
+            .ok_or(TarotErrorKind::InvalidCard)?;
+        let (value, suit) = code.split_at(index);
+        Ok(Self::new(
+            Suit::from_code(suit)?,
+            SuitValue::from_code(value)?,
+        ))
+    }
+}
+
 impl Points for Normal {
-    fn points(&self) -> OrderedFloat<f64> {
+    fn points(&self) -> HalfPoints {
         self.value.points()
     }
 }
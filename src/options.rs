@@ -1,4 +1,13 @@
+use crate::auction::Redeal;
+use crate::direction::Direction;
+use crate::family::Family;
+use crate::ismcts::Budget;
+use crate::rules::RuleSet;
+use crate::shuffle::Shuffle;
+use crate::strategy::Bot;
+
 #[derive(Debug, Copy, Clone, Eq, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Options {
     pub random: bool,
     pub auto: bool,
@@ -6,4 +15,13 @@ pub struct Options {
     pub no_slam: bool,
     pub test: bool,
     pub attack: bool,
+    pub bot: Bot,
+    pub budget: Budget,
+    pub direction: Direction,
+    pub shuffle: Shuffle,
+    pub redeal: Redeal,
+    pub rules: RuleSet,
+    pub family: Family,
+    // prints a JSON snapshot of the deals, with the serde feature
+    pub json: bool,
 }
@@ -1,17 +1,18 @@
 use derive_new::new;
-use ordered_float::OrderedFloat;
 use std::fmt;
 
-use crate::mode::Mode;
 use crate::options::Options;
+use crate::strategy::PlayerStrategy;
 
-#[derive(new, Eq, PartialEq, Clone, Debug)]
+#[derive(new, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct Player {
     name: String,
-    mode: Mode,
     options: Options,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    strategy: Box<dyn PlayerStrategy>,
     #[new(default)]
-    score: OrderedFloat<f64>,
+    score: i32,
 }
 
 impl fmt::Display for Player {
@@ -21,11 +22,11 @@ impl fmt::Display for Player {
 }
 
 impl Player {
-    pub fn add_score(&mut self, points: OrderedFloat<f64>) {
+    pub const fn add_score(&mut self, points: i32) {
         self.score += points;
     }
     #[must_use]
-    pub const fn score(&self) -> OrderedFloat<f64> {
+    pub const fn score(&self) -> i32 {
         self.score
     }
     #[must_use]
@@ -36,4 +37,8 @@ impl Player {
     pub const fn options(&self) -> &Options {
         &self.options
     }
+    #[must_use]
+    pub fn strategy(&self) -> &dyn PlayerStrategy {
+        self.strategy.as_ref()
+    }
 }
@@ -1,33 +1,37 @@
 use derive_new::new;
 use itertools::Itertools;
-use ordered_float::OrderedFloat;
-use rand::prelude::*;
-use rand_distr::Distribution;
+use rand::rngs::StdRng;
 use std::fmt;
 use strum::IntoEnumIterator;
 
 use crate::card::Card;
 use crate::contract::Contract;
 use crate::deck::Deck;
+use crate::discard_planner;
 use crate::errors::TarotErrorKind;
-use crate::handle::Handle;
-use crate::helpers::read_index;
+use crate::handle::{Handle, ShownHandle};
+use crate::knowledge::Knowledge;
+use crate::misere::{Misere, ShownMisere};
 use crate::mode::Mode;
 use crate::options::Options;
 use crate::player::Player;
-use crate::points::Points;
+use crate::points::{HalfPoints, Points};
 use crate::role::Role;
+use crate::slam_planner;
 use crate::suit::Suit;
 use crate::suit_value::SuitValue;
+use crate::table::Table;
 use crate::team::Team;
 use crate::traits::Representation;
 use crate::trump::Trump;
 use crate::turn::Turn;
 
 #[derive(new, Eq, PartialEq, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct PlayerInGame {
     mode: Mode,
     options: Options,
+    seat: usize,
     #[new(default)]
     slam: bool,
     #[new(default)]
@@ -39,19 +43,33 @@ pub struct PlayerInGame {
     #[new(default)]
     hand: Deck,
     #[new(default)]
+    table: Table,
+    #[new(default)]
     owned: Deck,
     #[new(default)]
     callee: Option<Card>,
     #[new(default)]
     handle: Option<Handle>,
+    #[new(default)]
+    taker_index: Option<usize>,
+    #[new(default)]
+    shown_handle: Option<ShownHandle>,
+    #[new(default)]
+    knowledge: Knowledge,
 }
 
 impl fmt::Display for PlayerInGame {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        if let Some(role) = &self.role {
+        // RULE: the partner of the taker stays secret until the called card is played
+        let secret = self.role == Some(Role::Ally) && self.knowledge.ally().is_none();
+        if let Some(role) = &self.role
+            && !secret
+        {
             writeln!(f, "Role : {role}")?;
         }
-        if let Some(team) = &self.team {
+        if let Some(team) = &self.team
+            && !secret
+        {
             writeln!(f, "Team : {team}")?;
         }
         if let Some(callee) = &self.callee {
@@ -65,7 +83,7 @@ impl fmt::Display for PlayerInGame {
 }
 
 impl Points for PlayerInGame {
-    fn points(&self) -> OrderedFloat<f64> {
+    fn points(&self) -> HalfPoints {
         self.owned.points()
     }
 }
@@ -84,6 +102,39 @@ impl PlayerInGame {
     pub const fn set_role(&mut self, role: Role) {
         self.role = Some(role);
     }
+    pub const fn set_taker_index(&mut self, taker_index: usize) {
+        self.taker_index = Some(taker_index);
+    }
+    #[must_use]
+    pub const fn taker_index(&self) -> Option<usize> {
+        self.taker_index
+    }
+    #[must_use]
+    pub const fn seat(&self) -> usize {
+        self.seat
+    }
+    #[must_use]
+    pub const fn knowledge(&self) -> &Knowledge {
+        &self.knowledge
+    }
+    pub const fn knowledge_mut(&mut self) -> &mut Knowledge {
+        &mut self.knowledge
+    }
+    pub const fn take_shown_handle(&mut self) -> Option<ShownHandle> {
+        self.shown_handle.take()
+    }
+    #[must_use]
+    pub const fn left_to_discard(&self) -> usize {
+        self.mode.dog_size().saturating_sub(self.discard.len())
+    }
+    #[must_use]
+    pub const fn discarded(&self) -> &Deck {
+        &self.discard
+    }
+    pub fn take_from_hand(&mut self, card: &Card) -> Option<Card> {
+        let index = self.hand.iter().position(|held| held == card)?;
+        Some(self.hand.remove(index))
+    }
     #[must_use]
     pub const fn callee(&self) -> Option<Card> {
         self.callee
@@ -96,6 +147,10 @@ impl PlayerInGame {
         self.discard = discard.clone();
     }
     #[must_use]
+    pub const fn owned(&self) -> &Deck {
+        &self.owned
+    }
+    #[must_use]
     pub fn all_cards(&self) -> Deck {
         let mut all_cards = self.owned.clone();
         all_cards.extend(&self.discard);
@@ -104,6 +159,23 @@ impl PlayerInGame {
     pub fn push_owned(&mut self, card: Card) {
         self.owned.push(card);
     }
+    // RULE: at two, the last cards dealt are laid on the table, the face up ones are played as if in hand
+    pub fn deal(&mut self, cards: &Deck) -> Result<(), TarotErrorKind> {
+        let (hand, table) = cards.split_at(cards.len().saturating_sub(self.mode.table_size()));
+        if !table.is_empty() {
+            self.table = Table::lay(&Deck::new(table.to_vec()))?;
+            self.extend_hand(&self.table.shown());
+        }
+        self.extend_hand(&Deck::new(hand.to_vec()));
+        Ok(())
+    }
+    // every card dealt and not played yet, face down ones included
+    #[must_use]
+    pub fn held(&self) -> Deck {
+        let mut held = self.hand.clone();
+        held.extend(&self.table.hidden());
+        held
+    }
     pub fn extend_hand(&mut self, deck: &Deck) {
         self.hand.extend(deck);
         self.hand.sort();
@@ -128,13 +200,34 @@ impl PlayerInGame {
         &self.team
     }
     #[must_use]
+    pub const fn mode(&self) -> &Mode {
+        &self.mode
+    }
+    #[must_use]
+    pub const fn hand(&self) -> &Deck {
+        &self.hand
+    }
+    #[must_use]
+    pub const fn table(&self) -> &Table {
+        &self.table
+    }
+    #[must_use]
+    pub const fn options(&self) -> &Options {
+        &self.options
+    }
+    #[must_use]
     pub const fn handle(&self) -> &Option<Handle> {
         &self.handle
     }
-    pub fn points_for_oudlers(&self) -> Result<OrderedFloat<f64>, TarotErrorKind> {
-        self.owned.points_for_oudlers()
+    pub fn points_for_oudlers(&self) -> Result<HalfPoints, TarotErrorKind> {
+        self.owned.points_for_oudlers(&self.options.rules)
     }
-    pub fn play_card(&mut self, player: &Player, turn: &Turn) -> Result<Card, TarotErrorKind> {
+    pub fn play_card(
+        &mut self,
+        player: &Player,
+        turn: &Turn,
+        rng: &mut StdRng,
+    ) -> Result<Card, TarotErrorKind> {
         let Some(_) = self.role else {
             return Err(TarotErrorKind::NoRoleForPlayer(player.name().to_string()));
         };
@@ -143,7 +236,10 @@ impl PlayerInGame {
             return Err(TarotErrorKind::NoTeamForPlayer(player.name().to_string()));
         };
 
-        if (!self.owned.has_fool() && !self.owned.len().is_multiple_of(self.mode.players()))
+        // RULE: the player who won the fool's turn waits for a low card in exchange
+        if (!self.owned.has_fool()
+            && !self.owned.len().is_multiple_of(self.mode.players())
+            && !self.missing_card())
             || (self.owned.has_fool() && (self.owned.len() % self.mode.players() != 1))
         {
             eprintln!("{}", self.owned.len() % self.mode.players());
@@ -151,12 +247,15 @@ impl PlayerInGame {
         }
 
         if self.is_first_turn() {
-            self.announce_handle()?;
+            self.announce_handle(player, rng)?;
         }
 
         let player_name = player.name();
         if !self.options.quiet {
             println!("{player_name} with hand : {}", self.hand);
+            if !self.table.is_empty() {
+                println!("{player_name} with table : {}", self.table);
+            }
             println!("Must play one card, choices :");
         }
 
@@ -180,141 +279,94 @@ impl PlayerInGame {
             );
         }
 
-        let final_choice = if self.options.auto && possible_choices.len() == 1 {
-            possible_choices.first().ok_or(TarotErrorKind::NoCard(0))?
-        } else if self.options.random {
-            let random_index = rand::rng().random_range(0..possible_choices.len());
-            possible_choices
-                .get(random_index)
-                .ok_or(TarotErrorKind::NoCard(random_index))?
-        } else {
-            &loop {
-                let choice_index = read_index();
-                if possible_choices.contains(&choice_index) {
-                    break choice_index;
-                } else if !self.options.quiet {
-                    println!("Error, please retry");
-                }
+        let final_choice = player
+            .strategy()
+            .play(player, self, turn, possible_choices, rng)?;
+        if !possible_choices.contains(&final_choice) {
+            return Err(TarotErrorKind::IllegalChoice(format!(
+                "card at index {final_choice} cannot be played"
+            )));
+        }
+        let card = self.hand.remove(final_choice);
+        if let Some(uncovered) = self.table.play(&card) {
+            if !self.options.quiet {
+                println!("{player_name} turns up {uncovered}");
             }
-        };
-        Ok(self.hand.remove(*final_choice))
+            self.extend_hand(&Deck::new(vec![uncovered]));
+        }
+        Ok(card)
     }
     pub fn choose_contract_among(
         &self,
         player: &Player,
         contracts: &[Contract],
+        rng: &mut StdRng,
     ) -> Result<Option<Contract>, TarotErrorKind> {
         let player_name = player.name();
-        let contract = if self.options.auto && contracts.len() == 1 {
+        let Some(contract) = player.strategy().bid(player, self, contracts, rng)? else {
             return Ok(None);
-        } else if self.options.random {
-            if self.options.attack {
-                return Ok(None);
-            }
-            let random_choice_index = rand::rng().random_range(0..=contracts.len());
-            if random_choice_index == 0 {
-                return Ok(None);
-            }
-            let contract_index = random_choice_index - 1;
-            contracts
-                .get(contract_index)
-                .ok_or(TarotErrorKind::NoContract(contract_index))?
-        } else {
-            loop {
-                if !self.options.quiet {
-                    println!("{player_name} with hand : {}", &self.hand);
-                    println!("{player_name} must choose a contract, possibilities :");
-                    println!("\tPass : press 0");
-                    for (contract_index, contract) in contracts.iter().enumerate() {
-                        println!(
-                            "\t{} (x{}) : press {}",
-                            contract,
-                            contract.multiplier(),
-                            contract_index + 1
-                        );
-                    }
-                }
-                let contract_index = read_index();
-                if contract_index == 0 {
-                    return Ok(None);
-                } else if contract_index < contracts.len() + 1 {
-                    break contracts
-                        .get(contract_index - 1)
-                        .ok_or(TarotErrorKind::NoContract(contract_index - 1))?;
-                } else if !self.options.quiet {
-                    println!("Error, please retry");
-                }
-            }
         };
+        if !contracts.contains(&contract) {
+            return Err(TarotErrorKind::IllegalChoice(format!(
+                "contract {contract} is not available"
+            )));
+        }
         if !self.options.quiet {
             println!("{player_name} : {contract} (auto? : {})", self.options.auto);
         }
-        Ok(Some(*contract))
+        Ok(Some(contract))
     }
+    // slam announced by the taker after the discard
     #[must_use]
-    pub fn slam_bonus(&self) -> f64 {
-        if self.slam {
-            if self.owned.is_chelem() {
-                if !self.options.quiet {
-                    println!("{self} : chelem announced and realized !");
-                }
-                400.0
-            } else {
-                -200.0
-            }
-        } else if self.owned.is_chelem() {
-            if !self.options.quiet {
-                println!("{self} : chelem not announced but realized !");
-            }
-            200.0
-        } else if self.owned.is_empty() || self.owned.only_fool() {
-            -200.0
-        } else {
-            0.0
-        }
+    pub const fn slam(&self) -> bool {
+        self.slam
     }
-    pub fn announce_slam(&mut self) -> Result<bool, TarotErrorKind> {
+    pub fn announce_slam(
+        &mut self,
+        player: &Player,
+        rng: &mut StdRng,
+    ) -> Result<bool, TarotErrorKind> {
+        // in test mode, tell what the slam estimate would announce for calibration
+        if self.options.test && !self.options.quiet {
+            println!("{}", slam_planner::estimate(self));
+        }
         if self.options.no_slam {
             return Ok(false);
         }
-        let slams = [false, true];
-        self.slam = if self.options.random {
-            let weights = vec![99, 1];
-            let dist = rand_distr::weighted::WeightedAliasIndex::new(weights)?;
-            let mut rng = rand::rng();
-            let slam_index = dist.sample(&mut rng);
-            *slams
-                .get(slam_index)
-                .ok_or(TarotErrorKind::NoSlam(slam_index))?
-        } else {
-            loop {
-                if !self.options.quiet {
-                    println!("Hand of {} : {}", &self, &self.hand);
-                    println!("Slam ? : ");
-                    for (i, s) in slams.iter().enumerate() {
-                        println!("{s} : press {i}");
-                    }
-                }
-                let slam_index = read_index();
-                if slam_index < slams.len() {
-                    break *slams
-                        .get(slam_index)
-                        .ok_or(TarotErrorKind::NoSlam(slam_index))?;
-                } else if !self.options.quiet {
-                    println!("Error, please retry");
-                }
-            }
-        };
+        self.slam = player.strategy().slam(player, self, rng)?;
         Ok(self.slam)
     }
-    pub fn announce_handle(&mut self) -> Result<(), TarotErrorKind> {
-        let mut trumps = self.hand.trumps();
+    // RULE: misères are a house rule, announced with the dealt hand
+    pub fn announce_miseres(
+        &self,
+        player: &Player,
+        rng: &mut StdRng,
+    ) -> Result<Vec<ShownMisere>, TarotErrorKind> {
+        let mut shown = Vec::new();
+        if !self.options.rules.misere {
+            return Ok(shown);
+        }
+        for misere in Misere::available(&self.hand) {
+            if player.strategy().misere(player, self, misere, rng)? {
+                shown.push(ShownMisere::new(self.seat, misere, self.hand.clone()));
+            }
+        }
+        Ok(shown)
+    }
+    pub fn announce_handle(
+        &mut self,
+        player: &Player,
+        rng: &mut StdRng,
+    ) -> Result<(), TarotErrorKind> {
+        let mut trumps: Vec<Card> = self.hand.trumps().into_iter().copied().collect();
         let discarded_trumps = self.owned.trumps();
-        let mut total_trumps = trumps.len() + discarded_trumps.len();
-        let handle = self.mode.handle(total_trumps);
+        let handle = self
+            .options
+            .rules
+            .handle(self.mode, trumps.len() + discarded_trumps.len());
         self.handle = match handle {
             None => None,
-            Some(mut handle) => {
+            Some(handle) => {
                 let handles = match handle {
                     Handle::Simple => vec![Handle::Refused, Handle::Simple],
                     Handle::Double => vec![Handle::Refused, Handle::Simple, Handle::Double],
@@ -326,85 +378,43 @@ impl PlayerInGame {
                     ],
                     Handle::Refused => vec![],
                 };
-                handle = if self.options.random {
-                    let random_slam_index = rand::rng().random_range(0..handles.len());
-                    *handles
-                        .get(random_slam_index)
-                        .ok_or(TarotErrorKind::NoHandle(random_slam_index))?
-                } else {
-                    loop {
-                        if !self.options.quiet {
-                            for a in &trumps {
-                                println!("\t{a}");
-                            }
-                            println!(
-                                "You have {} trumps, you can declare a handle : ",
-                                trumps.len()
-                            );
-                            for (handle_index, handle) in handles.iter().enumerate() {
-                                println!(
-                                    "{handle} handle (needs: {} trumps, points: {}) : press {handle_index}",
-                                    self.mode.handle_limit(handle),
-                                    handle.points(),
-                                );
-                            }
-                        }
-                        let handle_index = read_index();
-                        if handle_index < handles.len() {
-                            break *handles
-                                .get(handle_index)
-                                .ok_or(TarotErrorKind::NoHandle(handle_index))?;
-                        } else if !self.options.quiet {
-                            println!("Error, please retry");
-                        }
-                    }
-                };
+                let handle = player
+                    .strategy()
+                    .handle(player, self, &trumps, &handles, rng)?;
+                if !handles.contains(&handle) {
+                    return Err(TarotErrorKind::IllegalChoice(format!(
+                        "handle {handle} is not available"
+                    )));
+                }
                 if handle != Handle::Refused {
+                    let limit = self.options.rules.handle_limit(self.mode, handle);
+                    let has_fool = trumps.iter().any(|card| card.is_fool());
                     trumps.retain(|&c| !c.is_fool());
-                    // RULE: cant use fool as trump when you have too much trumps for the handle
-                    if total_trumps != trumps.len() + discarded_trumps.len() && !self.options.quiet
-                    {
-                        println!("You can't use fool as trumps in a handle");
+                    trumps.extend(discarded_trumps.into_iter().copied());
+                    // RULE: the fool only completes a handle, showing it means no other trump is held
+                    if has_fool {
+                        if trumps.len() < limit {
+                            trumps.push(Card::Trump(Trump::Fool));
+                        } else if !self.options.quiet {
+                            println!("You can't use fool as trumps in a handle");
+                        }
                     }
-                    trumps.extend(discarded_trumps.iter());
-                    total_trumps = trumps.len();
-
-                    let limit = self.mode.handle_limit(&handle);
+                    let total_trumps = trumps.len();
                     if total_trumps > limit {
-                        let mut to_discard = total_trumps - limit;
-                        while to_discard > 0 {
-                            loop {
-                                if !self.options.quiet {
-                                    for (i, a) in trumps.iter().enumerate() {
-                                        println!("\t{i} : {a}");
-                                    }
-                                    println!(
-                                        "You must discards {to_discard} trumps to present only {limit}"
-                                    );
-                                }
-                                if self.options.random {
-                                    let index_to_remove = rand::rng().random_range(0..trumps.len());
-                                    trumps.remove(index_to_remove);
-                                    break;
-                                }
-                                let trump_index = read_index();
-                                if trump_index < trumps.len() {
-                                    trumps.remove(trump_index);
-                                } else if !self.options.quiet {
-                                    println!("Error, please retry");
-                                }
+                        while trumps.len() > limit {
+                            let trump_index = player
+                                .strategy()
+                                .hide_trump(player, self, &trumps, limit, rng)?;
+                            if trump_index >= trumps.len() {
+                                return Err(TarotErrorKind::NoCard(trump_index));
                             }
-                            to_discard -= 1;
+                            trumps.remove(trump_index);
                         }
                     } else if !self.options.quiet {
                         println!("You have exactly the good number of trumps");
                     }
-                    if !self.options.quiet {
-                        println!("Final handle : ");
-                        for a in &trumps {
-                            println!("\t{}", &a);
-                        }
-                    }
+                    self.shown_handle =
+                        Some(ShownHandle::new(self.seat, handle, Deck::new(trumps)));
                 }
                 Some(handle)
             }
@@ -419,31 +429,32 @@ impl PlayerInGame {
     }
     #[must_use]
     pub fn missing_card(&self) -> bool {
+        // at two, the single card of a fool's turn is already missing one
         !self.owned.has_fool()
-            && self.owned.len() > 1
+            && !self.owned.is_empty()
             && (self.owned.len() % self.mode.players()) == (self.mode.players() - 1)
     }
     pub fn give_low(&mut self) -> Option<Card> {
-        self.owned.give_low()
+        discard_planner::give_back(&mut self.owned, &mut self.discard)
     }
     #[must_use]
     pub fn count_oudlers(&self) -> usize {
         self.owned.count_oudlers()
     }
     #[must_use]
-    pub const fn is_first_turn(&self) -> bool {
-        self.mode.cards_per_player() == self.hand.len()
+    pub fn is_first_turn(&self) -> bool {
+        self.mode.cards_per_player() == self.hand.len() + self.table.hidden().len()
     }
     #[must_use]
     pub const fn last_turn(&self) -> bool {
         self.hand.is_empty()
     }
     #[must_use]
-    pub const fn before_last_turn(&self) -> bool {
-        self.hand.len() == 1
+    pub fn before_last_turn(&self) -> bool {
+        self.hand.len() == 1 && self.table.hidden().is_empty()
     }
 
-    pub fn call(&self) -> Result<Option<Card>, TarotErrorKind> {
+    pub fn call(&self, player: &Player, rng: &mut StdRng) -> Result<Option<Card>, TarotErrorKind> {
         if self.mode != Mode::Five {
             return Ok(None);
         }
@@ -470,73 +481,51 @@ impl PlayerInGame {
             .cartesian_product(value_callable.iter())
             .map(|(c, cv)| Card::normal(c, *cv))
             .collect();
-        let callee = if self.options.random {
-            let random_callee_index = rand::rng().random_range(0..choices.len());
-            choices
-                .get(random_callee_index)
-                .ok_or(TarotErrorKind::NoCard(random_callee_index))?
-        } else {
-            loop {
-                if !self.options.quiet {
-                    println!("Hand of taker {}", &self.hand);
-                    println!("Taker must choose a card to call his partner :");
-                    println!("Possibilities:");
-                    for (i, c) in choices.iter().enumerate() {
-                        println!("\t{c: <3} : press {i}");
-                    }
-                }
-                let choice_index = read_index();
-                if choice_index < choices.len() {
-                    break choices
-                        .get(choice_index)
-                        .ok_or(TarotErrorKind::NoCard(choice_index))?;
-                } else if !self.options.quiet {
-                    println!("Error, please retry");
-                }
-            }
-        };
+        let callee = player.strategy().call(player, self, &choices, rng)?;
+        if !choices.contains(&callee) {
+            return Err(TarotErrorKind::IllegalChoice(format!(
+                "card {callee} cannot be called"
+            )));
+        }
         if !self.options.quiet {
             println!("Called card for ally is {callee}");
         }
-        Ok(Some(*callee))
+        Ok(Some(callee))
     }
-    pub fn discard(&mut self) -> Result<(), TarotErrorKind> {
+    pub fn discard(&mut self, player: &Player, rng: &mut StdRng) -> Result<(), TarotErrorKind> {
         if !self.options.quiet {
             println!("{self}");
         }
-        let dog_size = self.mode.dog_size();
-        for current in 0..dog_size {
+        if !self.options.quiet
+            && let Some(explanation) = player.strategy().explain_discard(self)
+        {
+            println!("{explanation}");
+        }
+        while self.left_to_discard() > 0 {
+            let left = self.left_to_discard();
             if !self.options.quiet {
-                println!("You must discard {} cards", dog_size - current);
+                println!("You must discard {left} cards");
+            }
+            // RULE: cards of the table cannot be discarded
+            let discardables_indexes: Vec<usize> = self
+                .hand
+                .discardables(left)
+                .into_iter()
+                .filter(|index| {
+                    self.hand
+                        .get(*index)
+                        .is_none_or(|card| !self.table.shows(card))
+                })
+                .collect();
+            let discard_index =
+                player
+                    .strategy()
+                    .discard(player, self, &discardables_indexes, rng)?;
+            if !discardables_indexes.contains(&discard_index) {
+                return Err(TarotErrorKind::IllegalChoice(format!(
+                    "card at index {discard_index} cannot be discarded"
+                )));
             }
-            let discardables_indexes = self.hand.discardables(dog_size);
-            let discard_index = if self.options.random {
-                let random_discarded_index =
-                    rand::rng().random_range(0..discardables_indexes.len());
-                *discardables_indexes
-                    .get(random_discarded_index)
-                    .ok_or(TarotErrorKind::NoCard(random_discarded_index))?
-            } else {
-                loop {
-                    if !self.options.quiet {
-                        println!("Hand of taker: {}", self.hand);
-                        println!("Possibilities:");
-                        discardables_indexes
-                            .iter()
-                            .filter_map(|index| self.hand.get(*index).map(|value| (index, value)))
-                            .for_each(|(index, card)| {
-                                println!("\t{card: <4} : press {index}");
-                            });
-                        println!("Choose discard : {}", self.owned);
-                    }
-                    let discard_index = read_index();
-                    if discardables_indexes.contains(&discard_index) {
-                        break discard_index;
-                    } else if !self.options.quiet {
-                        println!("Error, please retry");
-                    }
-                }
-            };
             let discarded = self.hand.remove(discard_index);
             self.discard.push(discarded);
             if !self.options.quiet {
@@ -1,5 +1,59 @@
-use ordered_float::OrderedFloat;
+use derive_more::{Add, AddAssign, Neg, Sub, Sum};
+use std::fmt;
+
+/// Card points counted exactly, in halves of a point
+#[derive(
+    Default, Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Add, AddAssign, Sub, Neg, Sum,
+)]
+pub struct HalfPoints(i32);
+
+impl HalfPoints {
+    // worth of a low card
+    pub const HALF: Self = Self(1);
+    #[must_use]
+    pub const fn from_halves(halves: i32) -> Self {
+        Self(halves)
+    }
+    #[must_use]
+    pub const fn from_points(points: i32) -> Self {
+        Self(points * 2)
+    }
+    #[must_use]
+    pub const fn halves(self) -> i32 {
+        self.0
+    }
+    // RULE: a half point in the difference between made and needed points counts as a whole one
+    #[must_use]
+    pub const fn rounded(self) -> i32 {
+        i32::midpoint(self.0, self.0.signum())
+    }
+    #[must_use]
+    pub fn to_f64(self) -> f64 {
+        f64::from(self.0) / 2.0
+    }
+}
+
+impl fmt::Display for HalfPoints {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.0 % 2 == 0 {
+            write!(f, "{}", self.0 / 2)
+        } else {
+            write!(f, "{:.1}", self.to_f64())
+        }
+    }
+}
 
 pub trait Points {
-    fn points(&self) -> OrderedFloat<f64>;
+    fn points(&self) -> HalfPoints;
+}
+
+#[test]
+fn points_tests() {
+    assert_eq!(HalfPoints::from_halves(1).rounded(), 1);
+    assert_eq!(HalfPoints::from_halves(-1).rounded(), -1);
+    assert_eq!(HalfPoints::from_halves(4).rounded(), 2);
+    assert_eq!(HalfPoints::from_halves(-7).rounded(), -4);
+    assert_eq!(HalfPoints::default().rounded(), 0);
+    assert_eq!(HalfPoints::from_halves(-1).to_string(), "-0.5");
+    assert_eq!(HalfPoints::from_points(41).to_string(), "41");
 }
@@ -0,0 +1,163 @@
+use derive_new::new;
+use rand::prelude::*;
+use rand::rngs::StdRng;
+use rand_distr::Distribution;
+
+use crate::card::Card;
+use crate::contract::Contract;
+use crate::deck::Deck;
+use crate::errors::TarotErrorKind;
+use crate::handle::Handle;
+use crate::misere::Misere;
+use crate::player::Player;
+use crate::player_in_game::PlayerInGame;
+use crate::strategy::PlayerStrategy;
+use crate::tarock_contract::TarockContract;
+use crate::tarock_strategy::TarockStrategy;
+use crate::turn::Turn;
+
+#[derive(new, Debug, Default, Copy, Clone)]
+pub struct RandomStrategy {
+    // RULE: in attack mode, bots always pass and let the human take
+    attack: bool,
+}
+
+impl PlayerStrategy for RandomStrategy {
+    fn bid(
+        &self,
+        _player: &Player,
+        _in_game: &PlayerInGame,
+        contracts: &[Contract],
+        rng: &mut StdRng,
+    ) -> Result<Option<Contract>, TarotErrorKind> {
+        if self.attack {
+            return Ok(None);
+        }
+        let random_choice_index = rng.random_range(0..=contracts.len());
+        if random_choice_index == 0 {
+            return Ok(None);
+        }
+        let contract_index = random_choice_index - 1;
+        Ok(Some(
+            *contracts
+                .get(contract_index)
+                .ok_or(TarotErrorKind::NoContract(contract_index))?,
+        ))
+    }
+    fn slam(
+        &self,
+        _player: &Player,
+        _in_game: &PlayerInGame,
+        rng: &mut StdRng,
+    ) -> Result<bool, TarotErrorKind> {
+        let slams = [false, true];
+        let weights = vec![99, 1];
+        let dist = rand_distr::weighted::WeightedAliasIndex::new(weights)?;
+        let slam_index = dist.sample(rng);
+        Ok(*slams
+            .get(slam_index)
+            .ok_or(TarotErrorKind::NoSlam(slam_index))?)
+    }
+    fn misere(
+        &self,
+        _player: &Player,
+        _in_game: &PlayerInGame,
+        _misere: Misere,
+        rng: &mut StdRng,
+    ) -> Result<bool, TarotErrorKind> {
+        Ok(rng.random_bool(0.5))
+    }
+    fn handle(
+        &self,
+        _player: &Player,
+        _in_game: &PlayerInGame,
+        _trumps: &[Card],
+        handles: &[Handle],
+        rng: &mut StdRng,
+    ) -> Result<Handle, TarotErrorKind> {
+        let random_handle_index = rng.random_range(0..handles.len());
+        Ok(*handles
+            .get(random_handle_index)
+            .ok_or(TarotErrorKind::NoHandle(random_handle_index))?)
+    }
+    fn hide_trump(
+        &self,
+        _player: &Player,
+        _in_game: &PlayerInGame,
+        trumps: &[Card],
+        _limit: usize,
+        rng: &mut StdRng,
+    ) -> Result<usize, TarotErrorKind> {
+        Ok(rng.random_range(0..trumps.len()))
+    }
+    fn call(
+        &self,
+        _player: &Player,
+        _in_game: &PlayerInGame,
+        choices: &[Card],
+        rng: &mut StdRng,
+    ) -> Result<Card, TarotErrorKind> {
+        let random_callee_index = rng.random_range(0..choices.len());
+        Ok(*choices
+            .get(random_callee_index)
+            .ok_or(TarotErrorKind::NoCard(random_callee_index))?)
+    }
+    fn discard(
+        &self,
+        _player: &Player,
+        _in_game: &PlayerInGame,
+        discardables: &[usize],
+        rng: &mut StdRng,
+    ) -> Result<usize, TarotErrorKind> {
+        let random_discarded_index = rng.random_range(0..discardables.len());
+        Ok(*discardables
+            .get(random_discarded_index)
+            .ok_or(TarotErrorKind::NoCard(random_discarded_index))?)
+    }
+    fn play(
+        &self,
+        _player: &Player,
+        _in_game: &PlayerInGame,
+        _turn: &Turn,
+        choices: &[usize],
+        rng: &mut StdRng,
+    ) -> Result<usize, TarotErrorKind> {
+        let random_index = rng.random_range(0..choices.len());
+        Ok(*choices
+            .get(random_index)
+            .ok_or(TarotErrorKind::NoCard(random_index))?)
+    }
+}
+
+impl TarockStrategy for RandomStrategy {
+    fn tarock_bid(
+        &self,
+        _player: &Player,
+        _in_game: &PlayerInGame,
+        contracts: &[TarockContract],
+        rng: &mut StdRng,
+    ) -> Result<Option<TarockContract>, TarotErrorKind> {
+        if self.attack {
+            return Ok(None);
+        }
+        let random_choice_index = rng.random_range(0..=contracts.len());
+        if random_choice_index == 0 {
+            return Ok(None);
+        }
+        let contract_index = random_choice_index - 1;
+        Ok(Some(
+            *contracts
+                .get(contract_index)
+                .ok_or(TarotErrorKind::NoContract(contract_index))?,
+        ))
+    }
+    fn talon(
+        &self,
+        _player: &Player,
+        _in_game: &PlayerInGame,
+        halves: &[Deck],
+        rng: &mut StdRng,
+    ) -> Result<usize, TarotErrorKind> {
+        Ok(rng.random_range(0..halves.len()))
+    }
+}
@@ -0,0 +1,53 @@
+use derive_new::new;
+
+use crate::contract::Contract;
+use crate::handle::ShownHandle;
+use crate::misere::ShownMisere;
+use crate::rules::RuleSet;
+use crate::slam::SlamOutcome;
+
+/// Public facts and result of a finished deal, seats are the ones of this deal
+#[derive(new, Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DealRecord {
+    taker: usize,
+    contract: Contract,
+    handles: Vec<ShownHandle>,
+    miseres: Vec<ShownMisere>,
+    slam: SlamOutcome,
+    // points of the taker before the ratio of the mode, negative when the contract fails
+    points: i32,
+    rules: RuleSet,
+}
+
+impl DealRecord {
+    #[must_use]
+    pub const fn taker(&self) -> usize {
+        self.taker
+    }
+    #[must_use]
+    pub const fn contract(&self) -> Contract {
+        self.contract
+    }
+    #[must_use]
+    pub fn handles(&self) -> &[ShownHandle] {
+        &self.handles
+    }
+    #[must_use]
+    pub fn miseres(&self) -> &[ShownMisere] {
+        &self.miseres
+    }
+    #[must_use]
+    pub const fn slam(&self) -> SlamOutcome {
+        self.slam
+    }
+    #[must_use]
+    pub const fn points(&self) -> i32 {
+        self.points
+    }
+    // rules the deal was scored with
+    #[must_use]
+    pub const fn rules(&self) -> &RuleSet {
+        &self.rules
+    }
+}
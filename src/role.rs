@@ -1,6 +1,7 @@
 use strum::Display;
 
 #[derive(Display, Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Role {
     Taker,
     Ally,
@@ -0,0 +1,249 @@
+use serde::Deserialize;
+use std::fs;
+use std::path::Path;
+use std::str::FromStr;
+use strum::{Display, EnumString};
+
+use crate::contract::Contract;
+use crate::errors::TarotErrorKind;
+use crate::handle::Handle;
+use crate::mode::Mode;
+use crate::points::HalfPoints;
+use crate::slam::SlamOutcome;
+
+/// Rule sets known by name
+#[derive(Default, Display, EnumString, Debug, Copy, Clone, Eq, PartialEq)]
+#[strum(serialize_all = "lowercase")]
+pub enum Preset {
+    #[default]
+    Fft,
+    House,
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Deserialize)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[serde(default, deny_unknown_fields)]
+pub struct Multipliers {
+    pub petite: i32,
+    pub garde: i32,
+    pub garde_sans: i32,
+    pub garde_contre: i32,
+}
+
+impl Default for Multipliers {
+    fn default() -> Self {
+        Self {
+            petite: 1,
+            garde: 2,
+            garde_sans: 4,
+            garde_contre: 6,
+        }
+    }
+}
+
+/// Points of the simple, double and triple handles, and the trumps each one needs by mode
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Deserialize)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[serde(default, deny_unknown_fields)]
+pub struct HandleRules {
+    pub points: [i32; 3],
+    pub two: [usize; 3],
+    pub three: [usize; 3],
+    pub four: [usize; 3],
+    pub five: [usize; 3],
+}
+
+impl Default for HandleRules {
+    fn default() -> Self {
+        Self {
+            points: [20, 30, 40],
+            two: [15, 18, 20],
+            three: [13, 15, 18],
+            four: [10, 13, 15],
+            five: [8, 10, 13],
+        }
+    }
+}
+
+/// Scoring values and variant switches of a table, the FFT 2012 rules by default
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Deserialize)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[serde(default, deny_unknown_fields)]
+pub struct RuleSet {
+    pub base: i32,
+    pub multipliers: Multipliers,
+    // points needed by the taker with 0, 1, 2 or 3 oudlers
+    pub needed: [i32; 4],
+    pub handles: HandleRules,
+    pub petit_au_bout: i32,
+    pub petit_au_bout_multiplied: bool,
+    pub announced_slam: i32,
+    pub unannounced_slam: i32,
+    pub failed_slam: i32,
+    pub misere: bool,
+    pub misere_points: i32,
+}
+
+impl Default for RuleSet {
+    fn default() -> Self {
+        Self {
+            base: 25,
+            multipliers: Multipliers::default(),
+            needed: [56, 51, 41, 36],
+            handles: HandleRules::default(),
+            petit_au_bout: 10,
+            petit_au_bout_multiplied: true,
+            announced_slam: 400,
+            unannounced_slam: 200,
+            failed_slam: 200,
+            misere: false,
+            misere_points: 10,
+        }
+    }
+}
+
+impl From<Preset> for RuleSet {
+    fn from(preset: Preset) -> Self {
+        match preset {
+            Preset::Fft => Self::default(),
+            // a common club table : misères allowed and a petit au bout which is not multiplied
+            Preset::House => Self {
+                petit_au_bout_multiplied: false,
+                misere: true,
+                ..Self::default()
+            },
+        }
+    }
+}
+
+impl RuleSet {
+    pub fn load(path: &Path) -> Result<Self, TarotErrorKind> {
+        let content = fs::read_to_string(path).map_err(|error| {
+            TarotErrorKind::InvalidRules(format!("{}: {error}", path.display()))
+        })?;
+        toml::from_str(&content).map_err(|error| TarotErrorKind::InvalidRules(error.to_string()))
+    }
+    // a preset name, or the path of a TOML file
+    pub fn from_arg(arg: &str) -> Result<Self, TarotErrorKind> {
+        Preset::from_str(arg)
+            .map_or_else(|_| Self::load(Path::new(arg)), |preset| Ok(preset.into()))
+    }
+    #[must_use]
+    pub const fn multiplier(&self, contract: Contract) -> i32 {
+        match contract {
+            Contract::Petite => self.multipliers.petite,
+            Contract::Garde => self.multipliers.garde,
+            Contract::GardeSans => self.multipliers.garde_sans,
+            Contract::GardeContre => self.multipliers.garde_contre,
+        }
+    }
+    #[must_use]
+    pub fn needed(&self, oudlers: usize) -> Option<HalfPoints> {
+        self.needed
+            .get(oudlers)
+            .map(|points| HalfPoints::from_points(*points))
+    }
+    // RULE: the rounded difference and the base are multiplied, the contract is made at 0
+    #[must_use]
+    pub const fn contract_points(&self, contract: Contract, difference: HalfPoints) -> i32 {
+        let base = if difference.halves() >= 0 {
+            self.base
+        } else {
+            -self.base
+        };
+        (difference.rounded() + base) * self.multiplier(contract)
+    }
+    #[must_use]
+    pub const fn petit_au_bout(&self, contract: Contract) -> i32 {
+        if self.petit_au_bout_multiplied {
+            self.petit_au_bout * self.multiplier(contract)
+        } else {
+            self.petit_au_bout
+        }
+    }
+    // RULE: slam bonuses are not multiplied by the contract
+    #[must_use]
+    pub const fn slam_bonus(&self, outcome: SlamOutcome) -> i32 {
+        match outcome {
+            SlamOutcome::None => 0,
+            SlamOutcome::Announced => self.announced_slam,
+            SlamOutcome::Unannounced => self.unannounced_slam,
+            SlamOutcome::Failed => -self.failed_slam,
+            SlamOutcome::Defense => -self.unannounced_slam,
+        }
+    }
+    // index of a handle in the arrays of the rules
+    const fn handle_index(handle: Handle) -> Option<usize> {
+        match handle {
+            Handle::Refused => None,
+            Handle::Simple => Some(0),
+            Handle::Double => Some(1),
+            Handle::Triple => Some(2),
+        }
+    }
+    #[must_use]
+    pub fn handle_bonus(&self, handle: Handle) -> i32 {
+        Self::handle_index(handle)
+            .and_then(|index| self.handles.points.get(index))
+            .copied()
+            .unwrap_or(0)
+    }
+    #[must_use]
+    pub fn handle_limit(&self, mode: Mode, handle: Handle) -> usize {
+        let limits = match mode {
+            Mode::Two => &self.handles.two,
+            Mode::Three => &self.handles.three,
+            Mode::Four => &self.handles.four,
+            Mode::Five => &self.handles.five,
+            Mode::Six => return self.handle_limit(mode.seated(), handle),
+        };
+        Self::handle_index(handle)
+            .and_then(|index| limits.get(index))
+            .copied()
+            .unwrap_or(0)
+    }
+    // highest handle a number of trumps allows
+    #[must_use]
+    pub fn handle(&self, mode: Mode, trumps: usize) -> Option<Handle> {
+        [Handle::Triple, Handle::Double, Handle::Simple]
+            .into_iter()
+            .find(|handle| trumps >= self.handle_limit(mode, *handle))
+    }
+}
+
+#[test]
+fn rules_tests() -> Result<(), TarotErrorKind> {
+    let fft = RuleSet::default();
+    assert_eq!(
+        fft.contract_points(Contract::Garde, HalfPoints::from_halves(-1)),
+        -52
+    );
+    assert_eq!(
+        fft.contract_points(Contract::Petite, HalfPoints::default()),
+        25
+    );
+    assert_eq!(fft.handle(Mode::Four, 9), None);
+    assert_eq!(fft.handle(Mode::Four, 13), Some(Handle::Double));
+    assert_eq!(fft.handle(Mode::Three, 18), Some(Handle::Triple));
+    assert_eq!(fft.needed(2), Some(HalfPoints::from_points(41)));
+    assert!(fft.slam_bonus(SlamOutcome::Announced) > fft.slam_bonus(SlamOutcome::Unannounced));
+    assert!(fft.slam_bonus(SlamOutcome::Defense) < 0);
+
+    let house = RuleSet::from_arg("house")?;
+    assert!(house.misere);
+    assert_eq!(house.petit_au_bout(Contract::GardeSans), 10);
+
+    let custom: RuleSet = toml::from_str(
+        "base = 20\nneeded = [56, 51, 41, 36]\n[multipliers]\ngarde_contre = 8\n[handles]\npoints = [10, 20, 30]\n",
+    )
+    .map_err(|error| TarotErrorKind::InvalidRules(error.to_string()))?;
+    assert_eq!(custom.multiplier(Contract::GardeContre), 8);
+    assert_eq!(custom.multiplier(Contract::Garde), 2);
+    assert_eq!(custom.handle_bonus(Handle::Simple), 10);
+    assert!(toml::from_str::<RuleSet>("bonus = 3").is_err());
+    assert_eq!(
+        fft.handle_limit(Mode::Six, Handle::Double),
+        fft.handle_limit(Mode::Five, Handle::Double)
+    );
+    Ok(())
+}
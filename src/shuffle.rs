@@ -0,0 +1,116 @@
+use rand::Rng;
+use rand::rngs::StdRng;
+use strum::{Display, EnumString};
+
+use crate::card::Card;
+use crate::deck::Deck;
+
+// RULE: the cut leaves at least 3 cards on each side
+const MIN_CUT: usize = 3;
+const MAX_OVERHAND_PACKET: usize = 8;
+
+/// What happens to the gathered cards between two deals
+#[derive(Default, Display, EnumString, Debug, Copy, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[strum(serialize_all = "lowercase")]
+pub enum Shuffle {
+    // a new random deck for each deal
+    #[default]
+    Random,
+    None,
+    Cut,
+    Riffle,
+    Overhand,
+}
+
+// the cut of a real hand is close to the middle of the deck
+fn cut_index(len: usize, rng: &mut StdRng) -> usize {
+    let index = (0..len).filter(|_| rng.random_bool(0.5)).count();
+    index.clamp(MIN_CUT, len.saturating_sub(MIN_CUT))
+}
+
+fn cut(cards: &mut [Card], rng: &mut StdRng) {
+    let index = cut_index(cards.len(), rng);
+    cards.rotate_left(index);
+}
+
+// Gilbert-Shannon-Reeds model : each card drops from a packet with a chance proportional to its size
+fn riffle(cards: &[Card], rng: &mut StdRng) -> Vec<Card> {
+    let (left, right) = cards.split_at(cut_index(cards.len(), rng));
+    let (mut left, mut right) = (left.iter(), right.iter());
+    let mut shuffled = Vec::with_capacity(cards.len());
+    while left.len() + right.len() > 0 {
+        let next = if rng.random_range(0..left.len() + right.len()) < left.len() {
+            left.next()
+        } else {
+            right.next()
+        };
+        shuffled.extend(next);
+    }
+    shuffled
+}
+
+// small packets are taken from the top and dropped on the new pile, reversing their order
+fn overhand(cards: &[Card], rng: &mut StdRng) -> Vec<Card> {
+    let mut shuffled = Vec::with_capacity(cards.len());
+    let mut rest = cards;
+    while !rest.is_empty() {
+        let size = rng.random_range(1..=MAX_OVERHAND_PACKET).min(rest.len());
+        let (packet, others) = rest.split_at(size);
+        shuffled.splice(0..0, packet.iter().copied());
+        rest = others;
+    }
+    shuffled
+}
+
+impl Shuffle {
+    // deck for the next deal, the gathered one is only kept by the physical models
+    #[must_use]
+    pub fn apply(self, gathered: Option<Deck>, rng: &mut StdRng) -> Deck {
+        self.apply_on(gathered, Deck::full(), rng)
+    }
+    // same, with a new pack of another game when there is nothing gathered
+    #[must_use]
+    pub fn apply_on(self, gathered: Option<Deck>, mut pack: Deck, rng: &mut StdRng) -> Deck {
+        let Some(gathered) = gathered.filter(|_| self != Self::Random) else {
+            pack.shuffle(rng);
+            return pack;
+        };
+        let mut cards: Vec<Card> = gathered.iter().copied().collect();
+        // RULE: the player before the dealer always cuts
+        match self {
+            Self::Random | Self::None => return gathered,
+            Self::Cut => {}
+            Self::Riffle => cards = riffle(&cards, rng),
+            Self::Overhand => cards = overhand(&cards, rng),
+        }
+        cut(&mut cards, rng);
+        Deck::new(cards)
+    }
+}
+
+#[test]
+fn shuffle_tests() {
+    use crate::constants::MAX_CARDS;
+    use rand::SeedableRng;
+
+    let mut rng = StdRng::seed_from_u64(5);
+    let deck = Deck::full();
+    assert_eq!(Shuffle::None.apply(Some(deck.clone()), &mut rng), deck);
+    assert_eq!(Shuffle::Riffle.apply(None, &mut rng).len(), MAX_CARDS);
+
+    let cut_deck = Shuffle::Cut.apply(Some(deck.clone()), &mut rng);
+    let index = cut_deck
+        .iter()
+        .position(|card| deck.first() == Some(card))
+        .unwrap_or_default();
+    assert!((MIN_CUT..=MAX_CARDS - MIN_CUT).contains(&(MAX_CARDS - index)));
+    for shuffle in [Shuffle::Riffle, Shuffle::Overhand] {
+        let mut shuffled = shuffle.apply(Some(deck.clone()), &mut rng);
+        assert_ne!(shuffled, deck);
+        shuffled.sort();
+        let mut sorted = deck.clone();
+        sorted.sort();
+        assert_eq!(shuffled, sorted);
+    }
+}
@@ -0,0 +1,191 @@
+use crate::card::Card;
+use crate::card_play;
+use crate::constants::MAX_POINTS;
+use crate::contract::Contract;
+use crate::deck::Deck;
+use crate::errors::TarotErrorKind;
+use crate::mode::Mode;
+use crate::options::Options;
+use crate::player_in_game::PlayerInGame;
+use crate::points::{HalfPoints, Points};
+use crate::role::Role;
+use crate::rules::RuleSet;
+use crate::team::Team;
+use crate::turn::Turn;
+
+// RULE: the owner of the fool gives a low card in exchange
+const EXCHANGED_POINTS: HalfPoints = HalfPoints::HALF;
+
+/// A deal where every hand is known, played until the end by the heuristic bot
+#[derive(Clone, Debug)]
+pub struct Simulation {
+    players: Vec<PlayerInGame>,
+    contract: Contract,
+    rules: RuleSet,
+    leader: usize,
+    turn: Turn,
+    attack_points: HalfPoints,
+    attack_oudlers: usize,
+}
+
+impl Simulation {
+    pub fn new(
+        mode: Mode,
+        contract: Contract,
+        rules: RuleSet,
+        taker_index: usize,
+        ally_index: Option<usize>,
+        callee: Option<Card>,
+        hands: &[Deck],
+    ) -> Self {
+        let options = Options {
+            quiet: true,
+            rules,
+            ..Options::default()
+        };
+        let players = hands
+            .iter()
+            .enumerate()
+            .map(|(seat, hand)| {
+                let mut player = PlayerInGame::new(mode, options, seat);
+                player.extend_hand(hand);
+                player.set_callee(callee);
+                player.set_taker_index(taker_index);
+                if seat == taker_index {
+                    player.set_team(Team::Attack);
+                    player.set_role(Role::Taker);
+                } else if Some(seat) == ally_index {
+                    player.set_team(Team::Attack);
+                    player.set_role(Role::Ally);
+                } else {
+                    player.set_team(Team::Defense);
+                    player.set_role(Role::Defenser);
+                }
+                player
+            })
+            .collect();
+        Self {
+            players,
+            contract,
+            rules,
+            // RULE: the first seat leads the first turn
+            leader: 0,
+            turn: Turn::default(),
+            attack_points: HalfPoints::default(),
+            attack_oudlers: 0,
+        }
+    }
+    // continue the deal from a turn in progress
+    pub fn resume(&mut self, turn: Turn, leader: usize) {
+        self.leader = turn.leader().unwrap_or(leader);
+        self.turn = turn;
+    }
+    fn is_attack(&self, seat: usize) -> bool {
+        self.players.get(seat).is_some_and(PlayerInGame::is_attack)
+    }
+    // cards won by a team outside of the turns : discard or dog
+    pub fn bank(&mut self, cards: &Deck, team: Team) {
+        if team == Team::Attack {
+            self.attack_points += cards.points();
+            self.attack_oudlers += cards.count_oudlers();
+        }
+    }
+    // give the cards of a finished turn to the team of its master
+    pub fn gather(&mut self, turn: &Turn, last: bool) -> Result<usize, TarotErrorKind> {
+        let winner = turn.master_player().ok_or(TarotErrorKind::NoMaster(0))?;
+        let winner_attack = self.is_attack(winner);
+        for (seat, card) in turn.plays() {
+            // RULE: the fool is always preserved to his owner, except in the last turn
+            let owner_attack = if card.is_fool() && !last {
+                let owner_attack = self.is_attack(seat);
+                if owner_attack != winner_attack {
+                    self.attack_points += if winner_attack {
+                        EXCHANGED_POINTS
+                    } else {
+                        -EXCHANGED_POINTS
+                    };
+                }
+                owner_attack
+            } else {
+                winner_attack
+            };
+            if owner_attack {
+                self.attack_points += card.points();
+                self.attack_oudlers += usize::from(card.is_oudler());
+            }
+        }
+        Ok(winner)
+    }
+    #[must_use]
+    pub const fn current(&self) -> usize {
+        (self.leader + self.turn.len()) % self.players.len()
+    }
+    #[must_use]
+    pub fn finished(&self) -> bool {
+        self.turn.is_empty() && self.players.iter().all(PlayerInGame::last_turn)
+    }
+    fn player(&self, seat: usize) -> Result<&PlayerInGame, TarotErrorKind> {
+        self.players.get(seat).ok_or(TarotErrorKind::NoPlayer(seat))
+    }
+    pub fn hand(&self, seat: usize) -> Result<&Deck, TarotErrorKind> {
+        Ok(self.player(seat)?.hand())
+    }
+    pub fn moves(&self) -> Result<Vec<Card>, TarotErrorKind> {
+        let player = self.player(self.current())?;
+        let hand = player.hand();
+        Ok(player
+            .choices(&self.turn)?
+            .iter()
+            .filter_map(|index| hand.get(*index).copied())
+            .collect())
+    }
+    pub fn play(&mut self, card: Card) -> Result<(), TarotErrorKind> {
+        let seat = self.current();
+        let player = self
+            .players
+            .get_mut(seat)
+            .ok_or(TarotErrorKind::NoPlayer(seat))?;
+        let card = player
+            .take_from_hand(&card)
+            .ok_or_else(|| TarotErrorKind::IllegalChoice(format!("{card} is not in hand")))?;
+        let last = player.last_turn();
+        self.turn.put(seat, card);
+        if self.turn.len() == self.players.len() {
+            let turn = std::mem::take(&mut self.turn);
+            self.leader = self.gather(&turn, last)?;
+        }
+        Ok(())
+    }
+    pub fn rollout(&mut self) -> Result<(), TarotErrorKind> {
+        while !self.finished() {
+            let player = self.player(self.current())?;
+            let choices = player.choices(&self.turn)?;
+            let index = card_play::play(player, &self.turn, &choices)?;
+            let card = *player
+                .hand()
+                .get(index)
+                .ok_or(TarotErrorKind::NoCard(index))?;
+            self.play(card)?;
+        }
+        Ok(())
+    }
+    // points of the taker, as counted at the end of a real deal without bonuses
+    #[must_use]
+    pub fn score(&self) -> i32 {
+        let todo = self.rules.needed(self.attack_oudlers).unwrap_or_default();
+        self.rules
+            .contract_points(self.contract, self.attack_points - todo)
+    }
+    // outcome between 0 and 1 for the team of the seat
+    #[must_use]
+    pub fn reward(&self, seat: usize) -> f64 {
+        let swing = MAX_POINTS.to_f64() + f64::from(self.rules.base);
+        let multiplier = f64::from(self.rules.multiplier(self.contract));
+        let attack = 0.5 + f64::from(self.score()) / multiplier / (2.0 * swing);
+        if self.is_attack(seat) {
+            attack
+        } else {
+            1.0 - attack
+        }
+    }
+}
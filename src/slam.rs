@@ -0,0 +1,41 @@
+use strum::Display;
+
+/// How the slam of a deal ended
+#[derive(Default, Display, Debug, Copy, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum SlamOutcome {
+    #[default]
+    #[strum(serialize = "no slam")]
+    None,
+    #[strum(serialize = "announced slam")]
+    Announced,
+    #[strum(serialize = "unannounced slam")]
+    Unannounced,
+    #[strum(serialize = "failed slam")]
+    Failed,
+    #[strum(serialize = "slam of the defense")]
+    Defense,
+}
+
+impl SlamOutcome {
+    // RULE: a slam is made by winning every turn, an announced one fails otherwise
+    #[must_use]
+    pub const fn new(announced: bool, attack_won_all: bool, defense_won_all: bool) -> Self {
+        match (announced, attack_won_all, defense_won_all) {
+            (true, true, _) => Self::Announced,
+            (true, false, _) => Self::Failed,
+            (false, true, _) => Self::Unannounced,
+            (false, false, true) => Self::Defense,
+            (false, false, false) => Self::None,
+        }
+    }
+}
+
+#[test]
+fn slam_tests() {
+    assert_eq!(SlamOutcome::new(false, false, false), SlamOutcome::None);
+    assert_eq!(SlamOutcome::new(true, true, false), SlamOutcome::Announced);
+    assert_eq!(SlamOutcome::new(true, false, true), SlamOutcome::Failed);
+    assert_eq!(SlamOutcome::new(false, false, true), SlamOutcome::Defense);
+    assert_eq!(SlamOutcome::Unannounced.to_string(), "unannounced slam");
+}
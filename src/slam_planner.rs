@@ -0,0 +1,204 @@
+use itertools::Itertools;
+use rand::SeedableRng;
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use std::fmt;
+use strum::IntoEnumIterator;
+
+use crate::card::Card;
+use crate::contract::Contract;
+use crate::deck::Deck;
+use crate::player_in_game::PlayerInGame;
+use crate::rules::RuleSet;
+use crate::suit::Suit;
+use crate::traits::Power;
+
+const SAMPLES: u32 = 200;
+// the estimate must not depend on the random decisions of the game
+const SEED: u64 = 0;
+
+/// Chance of the taker to win every turn, and what it would announce
+#[derive(Debug, Clone, PartialEq)]
+pub struct SlamEstimate {
+    master_trumps: usize,
+    master_suits: Vec<Suit>,
+    chance: f64,
+    rules: RuleSet,
+}
+
+impl SlamEstimate {
+    #[must_use]
+    pub const fn chance(&self) -> f64 {
+        self.chance
+    }
+    // RULE: an announced slam wins more when realized, but loses when failed
+    #[must_use]
+    pub fn announce(&self) -> bool {
+        let rules = &self.rules;
+        let announced = self.chance.mul_add(
+            f64::from(rules.announced_slam + rules.failed_slam),
+            -f64::from(rules.failed_slam),
+        );
+        announced > self.chance * f64::from(rules.unannounced_slam)
+    }
+}
+
+impl fmt::Display for SlamEstimate {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "Slam chance {:.0}% with {} master trumps",
+            self.chance * 100.0,
+            self.master_trumps
+        )?;
+        if !self.master_suits.is_empty() {
+            write!(f, ", master in {}", self.master_suits.iter().join(" "))?;
+        }
+        if self.announce() {
+            write!(f, ", would announce")
+        } else {
+            write!(f, ", would not announce")
+        }
+    }
+}
+
+fn sorted(cards: impl Iterator<Item = Card>) -> Vec<Card> {
+    let mut cards: Vec<Card> = cards.collect();
+    cards.sort_by_key(|card| std::cmp::Reverse(card.power()));
+    cards
+}
+
+// the leader plays its cards from the top, the others follow with their lowest card
+fn leads_all(own: &[Card], others: &mut [Vec<Card>]) -> bool {
+    own.iter().all(|card| {
+        others.iter_mut().all(|other| {
+            let beaten = other
+                .first()
+                .is_none_or(|highest| highest.power() < card.power());
+            other.pop();
+            beaten
+        })
+    })
+}
+
+// RULE: the slammer leads every turn, trumps first to draw the ones of the defense
+fn wins_every_turn(hand: &Deck, hands: &[Deck]) -> bool {
+    let trumps = |deck: &Deck| {
+        sorted(
+            deck.iter()
+                .filter(|card| card.is_trump() && !card.is_fool())
+                .copied(),
+        )
+    };
+    let mut others: Vec<Vec<Card>> = hands.iter().map(trumps).collect();
+    if !leads_all(&trumps(hand), &mut others) || others.iter().any(|other| !other.is_empty()) {
+        return false;
+    }
+    Suit::iter().all(|suit| {
+        let of_suit = |deck: &Deck| {
+            sorted(
+                deck.iter()
+                    .filter(|card| matches!(card, Card::Normal(normal) if normal.suit() == &suit))
+                    .copied(),
+            )
+        };
+        let mut others: Vec<Vec<Card>> = hands.iter().map(of_suit).collect();
+        leads_all(&of_suit(hand), &mut others)
+    })
+}
+
+// own cards which no card outside can beat
+fn masters(hand: &Deck, unknown: &[Card], of: impl Fn(&Card) -> bool) -> usize {
+    let highest = unknown
+        .iter()
+        .filter(|card| of(card))
+        .map(Power::power)
+        .max()
+        .unwrap_or(0);
+    hand.iter()
+        .filter(|card| of(card) && card.power() > highest)
+        .count()
+}
+
+#[must_use]
+pub fn estimate(in_game: &PlayerInGame) -> SlamEstimate {
+    let mode = *in_game.mode();
+    let hand = in_game.hand();
+    // RULE: the taker only knows the dog in a petite or a garde
+    let seen = matches!(
+        in_game.knowledge().contract(),
+        Some(Contract::Petite | Contract::Garde)
+    );
+    let out = |card: &Card| hand.has(card) || (seen && in_game.discarded().has(card));
+    let unknown: Vec<Card> = Deck::full()
+        .iter()
+        .filter(|card| !out(card))
+        .copied()
+        .collect();
+
+    let master_trumps = masters(hand, &unknown, |card| card.is_trump() && !card.is_fool());
+    let master_suits = Suit::iter()
+        .filter(|suit| {
+            let of_suit =
+                |card: &Card| matches!(card, Card::Normal(normal) if normal.suit() == suit);
+            let length = hand.count_suit(*suit);
+            length > 0 && masters(hand, &unknown, of_suit) == length
+        })
+        .collect();
+
+    let mut rng = StdRng::seed_from_u64(SEED);
+    let mut successes = 0;
+    for _ in 0..SAMPLES {
+        let mut cards = unknown.clone();
+        cards.shuffle(&mut rng);
+        let hands: Vec<Deck> = cards
+            .chunks(mode.cards_per_player())
+            .take(mode.players() - 1)
+            .map(|chunk| Deck::new(chunk.to_vec()))
+            .collect();
+        if wins_every_turn(hand, &hands) {
+            successes += 1;
+        }
+    }
+    SlamEstimate {
+        master_trumps,
+        master_suits,
+        chance: f64::from(successes) / f64::from(SAMPLES),
+        rules: in_game.options().rules,
+    }
+}
+
+#[test]
+fn slam_planner_tests() {
+    use crate::mode::Mode;
+    use crate::options::Options;
+    use crate::suit_value::SuitValue;
+    use crate::trump::Trump;
+
+    let mut in_game = PlayerInGame::new(Mode::Three, Options::default(), 0);
+    in_game.knowledge_mut().set_contract(Contract::GardeSans);
+    let mut cards: Vec<Card> = Trump::iter().map(Card::Trump).collect();
+    cards.extend(
+        [SuitValue::King, SuitValue::Queen]
+            .into_iter()
+            .map(|value| Card::normal(Suit::Heart, value)),
+    );
+    in_game.extend_hand(&Deck::new(cards));
+    let certain = estimate(&in_game);
+    assert_eq!(certain.master_trumps, 21);
+    assert_eq!(certain.master_suits, vec![Suit::Heart]);
+    assert!((certain.chance() - 1.0).abs() < f64::EPSILON);
+    assert!(certain.announce());
+
+    let mut weak = PlayerInGame::new(Mode::Four, Options::default(), 0);
+    weak.extend_hand(&Deck::new(vec![
+        Card::Trump(Trump::_21),
+        Card::Trump(Trump::_3),
+        Card::normal(Suit::Club, SuitValue::King),
+        Card::normal(Suit::Club, SuitValue::_2),
+    ]));
+    let hopeless = estimate(&weak);
+    assert_eq!(hopeless.master_trumps, 1);
+    assert!(hopeless.chance() < 0.01);
+    assert!(!hopeless.announce());
+}
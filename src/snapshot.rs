@@ -0,0 +1,109 @@
+use serde::{Deserialize, Serialize};
+
+use crate::card::Card;
+use crate::contract::Contract;
+use crate::deck::Deck;
+use crate::errors::TarotErrorKind;
+use crate::handle::ShownHandle;
+use crate::mode::Mode;
+use crate::role::Role;
+use crate::team::Team;
+use crate::turn::Turn;
+
+/// A seat of a deal in progress, with every card it holds or won
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct SeatSnapshot {
+    pub name: String,
+    pub score: i32,
+    pub role: Option<Role>,
+    pub team: Option<Team>,
+    pub hand: Deck,
+    // face down cards of a two players table
+    pub hidden: Deck,
+    pub owned: Deck,
+    pub discard: Deck,
+}
+
+/// State of a deal in progress as seen from above the table, for other tools
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct DealSnapshot {
+    pub mode: Mode,
+    pub contract: Contract,
+    pub taker: usize,
+    pub callee: Option<Card>,
+    pub leader: usize,
+    pub dog: Deck,
+    pub handles: Vec<ShownHandle>,
+    // tricks played so far, in order
+    pub tricks: Vec<Turn>,
+    pub seats: Vec<SeatSnapshot>,
+}
+
+impl DealSnapshot {
+    pub fn to_json(&self) -> Result<String, TarotErrorKind> {
+        serde_json::to_string(self).map_err(|e| TarotErrorKind::Serialization(e.to_string()))
+    }
+    pub fn from_json(json: &str) -> Result<Self, TarotErrorKind> {
+        serde_json::from_str(json).map_err(|e| TarotErrorKind::Serialization(e.to_string()))
+    }
+}
+
+#[test]
+fn snapshot_tests() -> Result<(), TarotErrorKind> {
+    use crate::game::Game;
+    use crate::options::Options;
+    use crate::suit::Suit;
+    use crate::suit_value::SuitValue;
+    use crate::trump::Trump;
+
+    let king = Card::normal(Suit::Club, SuitValue::King);
+    let mut turn = Turn::default();
+    turn.put(1, Card::Trump(Trump::_21));
+    turn.put(0, king);
+    let seat = SeatSnapshot {
+        name: "South".to_string(),
+        score: -50,
+        role: Some(Role::Taker),
+        team: Some(Team::Attack),
+        hand: Deck::new(vec![Card::Trump(Trump::Fool)]),
+        hidden: Deck::default(),
+        owned: Deck::new(vec![king]),
+        discard: Deck::default(),
+    };
+    let snapshot = DealSnapshot {
+        mode: Mode::Four,
+        contract: Contract::Garde,
+        taker: 0,
+        callee: None,
+        leader: 1,
+        dog: Deck::default(),
+        handles: Vec::new(),
+        tricks: vec![turn],
+        seats: vec![seat],
+    };
+    let json = snapshot.to_json()?;
+    assert!(json.contains(r#""hand":["F"]"#));
+    assert!(json.contains(r#""cards":["T21","KC"]"#));
+    assert_eq!(DealSnapshot::from_json(&json)?, snapshot);
+    assert!(DealSnapshot::from_json("{}").is_err());
+
+    let options = Options {
+        random: true,
+        auto: true,
+        quiet: true,
+        test: true,
+        ..Options::default()
+    };
+    let mut game = Game::<{ Mode::Three.players() }>::new(options, 3)?;
+    game.start(2)?;
+    let json =
+        serde_json::to_value(&game).map_err(|e| TarotErrorKind::Serialization(e.to_string()))?;
+    let players = json.get("players").and_then(serde_json::Value::as_array);
+    assert_eq!(players.map(Vec::len), Some(3));
+    assert_eq!(
+        json.get("seed").and_then(serde_json::Value::as_u64),
+        Some(3)
+    );
+    assert!(json.get("rng").is_none());
+    Ok(())
+}
@@ -0,0 +1,297 @@
+use derive_new::new;
+use rand::rngs::StdRng;
+
+use crate::card::Card;
+use crate::contract::Contract;
+use crate::deck::Deck;
+use crate::errors::TarotErrorKind;
+use crate::handle::Handle;
+use crate::helpers::read_index;
+use crate::misere::Misere;
+use crate::player::Player;
+use crate::player_in_game::PlayerInGame;
+use crate::strategy::PlayerStrategy;
+use crate::tarock_contract::TarockContract;
+use crate::tarock_strategy::TarockStrategy;
+use crate::turn::Turn;
+
+#[derive(new, Debug, Default, Copy, Clone)]
+pub struct StdinStrategy {
+    quiet: bool,
+}
+
+impl StdinStrategy {
+    fn retry(self) {
+        if !self.quiet {
+            println!("Error, please retry");
+        }
+    }
+}
+
+impl PlayerStrategy for StdinStrategy {
+    fn bid(
+        &self,
+        player: &Player,
+        in_game: &PlayerInGame,
+        contracts: &[Contract],
+        _rng: &mut StdRng,
+    ) -> Result<Option<Contract>, TarotErrorKind> {
+        let player_name = player.name();
+        loop {
+            if !self.quiet {
+                println!("{player_name} with hand : {}", in_game.hand());
+                println!("{player_name} must choose a contract, possibilities :");
+                println!("\tPass : press 0");
+                for (contract_index, contract) in contracts.iter().enumerate() {
+                    println!(
+                        "\t{} (x{}) : press {}",
+                        contract,
+                        in_game.options().rules.multiplier(*contract),
+                        contract_index + 1
+                    );
+                }
+            }
+            let contract_index = read_index();
+            if contract_index == 0 {
+                return Ok(None);
+            } else if contract_index < contracts.len() + 1 {
+                return Ok(Some(
+                    *contracts
+                        .get(contract_index - 1)
+                        .ok_or(TarotErrorKind::NoContract(contract_index - 1))?,
+                ));
+            }
+            self.retry();
+        }
+    }
+    fn slam(
+        &self,
+        _player: &Player,
+        in_game: &PlayerInGame,
+        _rng: &mut StdRng,
+    ) -> Result<bool, TarotErrorKind> {
+        let slams = [false, true];
+        loop {
+            if !self.quiet {
+                println!("Hand of {} : {}", in_game, in_game.hand());
+                println!("Slam ? : ");
+                for (i, s) in slams.iter().enumerate() {
+                    println!("{s} : press {i}");
+                }
+            }
+            let slam_index = read_index();
+            if slam_index < slams.len() {
+                return Ok(*slams
+                    .get(slam_index)
+                    .ok_or(TarotErrorKind::NoSlam(slam_index))?);
+            }
+            self.retry();
+        }
+    }
+    fn misere(
+        &self,
+        _player: &Player,
+        in_game: &PlayerInGame,
+        misere: Misere,
+        _rng: &mut StdRng,
+    ) -> Result<bool, TarotErrorKind> {
+        let answers = [false, true];
+        loop {
+            if !self.quiet {
+                println!("Hand of {} : {}", in_game, in_game.hand());
+                println!("Announce {misere} ? : ");
+                for (i, answer) in answers.iter().enumerate() {
+                    println!("{answer} : press {i}");
+                }
+            }
+            let index = read_index();
+            if let Some(answer) = answers.get(index) {
+                return Ok(*answer);
+            }
+            self.retry();
+        }
+    }
+    fn handle(
+        &self,
+        _player: &Player,
+        in_game: &PlayerInGame,
+        trumps: &[Card],
+        handles: &[Handle],
+        _rng: &mut StdRng,
+    ) -> Result<Handle, TarotErrorKind> {
+        loop {
+            if !self.quiet {
+                for a in trumps {
+                    println!("\t{a}");
+                }
+                println!(
+                    "You have {} trumps, you can declare a handle : ",
+                    trumps.len()
+                );
+                for (handle_index, handle) in handles.iter().enumerate() {
+                    println!(
+                        "{handle} handle (needs: {} trumps, points: {}) : press {handle_index}",
+                        in_game
+                            .options()
+                            .rules
+                            .handle_limit(*in_game.mode(), *handle),
+                        in_game.options().rules.handle_bonus(*handle),
+                    );
+                }
+            }
+            let handle_index = read_index();
+            if handle_index < handles.len() {
+                return Ok(*handles
+                    .get(handle_index)
+                    .ok_or(TarotErrorKind::NoHandle(handle_index))?);
+            }
+            self.retry();
+        }
+    }
+    fn hide_trump(
+        &self,
+        _player: &Player,
+        _in_game: &PlayerInGame,
+        trumps: &[Card],
+        limit: usize,
+        _rng: &mut StdRng,
+    ) -> Result<usize, TarotErrorKind> {
+        loop {
+            if !self.quiet {
+                for (i, a) in trumps.iter().enumerate() {
+                    println!("\t{i} : {a}");
+                }
+                println!(
+                    "You must discards {} trumps to present only {limit}",
+                    trumps.len() - limit
+                );
+            }
+            let trump_index = read_index();
+            if trump_index < trumps.len() {
+                return Ok(trump_index);
+            }
+            self.retry();
+        }
+    }
+    fn call(
+        &self,
+        _player: &Player,
+        in_game: &PlayerInGame,
+        choices: &[Card],
+        _rng: &mut StdRng,
+    ) -> Result<Card, TarotErrorKind> {
+        loop {
+            if !self.quiet {
+                println!("Hand of taker {}", in_game.hand());
+                println!("Taker must choose a card to call his partner :");
+                println!("Possibilities:");
+                for (i, c) in choices.iter().enumerate() {
+                    println!("\t{c: <3} : press {i}");
+                }
+            }
+            let choice_index = read_index();
+            if choice_index < choices.len() {
+                return Ok(*choices
+                    .get(choice_index)
+                    .ok_or(TarotErrorKind::NoCard(choice_index))?);
+            }
+            self.retry();
+        }
+    }
+    fn discard(
+        &self,
+        _player: &Player,
+        in_game: &PlayerInGame,
+        discardables: &[usize],
+        _rng: &mut StdRng,
+    ) -> Result<usize, TarotErrorKind> {
+        loop {
+            if !self.quiet {
+                println!("Hand of taker: {}", in_game.hand());
+                println!("Possibilities:");
+                discardables
+                    .iter()
+                    .filter_map(|index| in_game.hand().get(*index).map(|value| (index, value)))
+                    .for_each(|(index, card)| {
+                        println!("\t{card: <4} : press {index}");
+                    });
+            }
+            let discard_index = read_index();
+            if discardables.contains(&discard_index) {
+                return Ok(discard_index);
+            }
+            self.retry();
+        }
+    }
+    fn play(
+        &self,
+        _player: &Player,
+        _in_game: &PlayerInGame,
+        _turn: &Turn,
+        choices: &[usize],
+        _rng: &mut StdRng,
+    ) -> Result<usize, TarotErrorKind> {
+        loop {
+            let choice_index = read_index();
+            if choices.contains(&choice_index) {
+                return Ok(choice_index);
+            }
+            self.retry();
+        }
+    }
+}
+
+impl TarockStrategy for StdinStrategy {
+    fn tarock_bid(
+        &self,
+        player: &Player,
+        in_game: &PlayerInGame,
+        contracts: &[TarockContract],
+        _rng: &mut StdRng,
+    ) -> Result<Option<TarockContract>, TarotErrorKind> {
+        let player_name = player.name();
+        loop {
+            if !self.quiet {
+                println!("{player_name} with hand : {}", in_game.hand());
+                println!("{player_name} must choose a game, possibilities :");
+                println!("\tPass : press 0");
+                for (contract_index, contract) in contracts.iter().enumerate() {
+                    println!(
+                        "\t{contract} ({}) : press {}",
+                        contract.value(),
+                        contract_index + 1
+                    );
+                }
+            }
+            let contract_index = read_index();
+            if contract_index == 0 {
+                return Ok(None);
+            } else if let Some(contract) = contracts.get(contract_index - 1) {
+                return Ok(Some(*contract));
+            }
+            self.retry();
+        }
+    }
+    fn talon(
+        &self,
+        _player: &Player,
+        in_game: &PlayerInGame,
+        halves: &[Deck],
+        _rng: &mut StdRng,
+    ) -> Result<usize, TarotErrorKind> {
+        loop {
+            if !self.quiet {
+                println!("Hand of declarer: {}", in_game.hand());
+                println!("Halves of the talon:");
+                for (i, half) in halves.iter().enumerate() {
+                    println!("\t{half} : press {i}");
+                }
+            }
+            let half_index = read_index();
+            if half_index < halves.len() {
+                return Ok(half_index);
+            }
+            self.retry();
+        }
+    }
+}
@@ -0,0 +1,121 @@
+use rand::rngs::StdRng;
+use std::fmt;
+use strum::{Display, EnumString};
+
+use crate::auto_strategy::AutoStrategy;
+use crate::card::Card;
+use crate::contract::Contract;
+use crate::errors::TarotErrorKind;
+use crate::handle::Handle;
+use crate::heuristic_strategy::HeuristicStrategy;
+use crate::ismcts_strategy::IsmctsStrategy;
+use crate::misere::Misere;
+use crate::options::Options;
+use crate::player::Player;
+use crate::player_in_game::PlayerInGame;
+use crate::random_strategy::RandomStrategy;
+use crate::stdin_strategy::StdinStrategy;
+use crate::turn::Turn;
+
+/// Every decision a seat has to take during a deal, rules are checked by the caller
+pub trait PlayerStrategy: fmt::Debug {
+    /// Choose a contract among the ones still available, None means pass
+    fn bid(
+        &self,
+        player: &Player,
+        in_game: &PlayerInGame,
+        contracts: &[Contract],
+        rng: &mut StdRng,
+    ) -> Result<Option<Contract>, TarotErrorKind>;
+    fn slam(
+        &self,
+        player: &Player,
+        in_game: &PlayerInGame,
+        rng: &mut StdRng,
+    ) -> Result<bool, TarotErrorKind>;
+    /// Whether to announce a misère the dealt hand allows, the whole hand is then shown
+    fn misere(
+        &self,
+        player: &Player,
+        in_game: &PlayerInGame,
+        misere: Misere,
+        rng: &mut StdRng,
+    ) -> Result<bool, TarotErrorKind>;
+    /// Choose a handle among the ones allowed by the number of trumps
+    fn handle(
+        &self,
+        player: &Player,
+        in_game: &PlayerInGame,
+        trumps: &[Card],
+        handles: &[Handle],
+        rng: &mut StdRng,
+    ) -> Result<Handle, TarotErrorKind>;
+    /// Index of a trump to hide when there are more trumps than needed for the handle
+    fn hide_trump(
+        &self,
+        player: &Player,
+        in_game: &PlayerInGame,
+        trumps: &[Card],
+        limit: usize,
+        rng: &mut StdRng,
+    ) -> Result<usize, TarotErrorKind>;
+    fn call(
+        &self,
+        player: &Player,
+        in_game: &PlayerInGame,
+        choices: &[Card],
+        rng: &mut StdRng,
+    ) -> Result<Card, TarotErrorKind>;
+    /// Reasons of the discard about to be made, for strategies which plan it
+    fn explain_discard(&self, _in_game: &PlayerInGame) -> Option<String> {
+        None
+    }
+    /// Index in hand of the next card to discard
+    fn discard(
+        &self,
+        player: &Player,
+        in_game: &PlayerInGame,
+        discardables: &[usize],
+        rng: &mut StdRng,
+    ) -> Result<usize, TarotErrorKind>;
+    /// Index in hand of the card to play
+    fn play(
+        &self,
+        player: &Player,
+        in_game: &PlayerInGame,
+        turn: &Turn,
+        choices: &[usize],
+        rng: &mut StdRng,
+    ) -> Result<usize, TarotErrorKind>;
+}
+
+#[derive(Default, Display, EnumString, Debug, Copy, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[strum(serialize_all = "lowercase")]
+pub enum Bot {
+    #[default]
+    Random,
+    Heuristic,
+    Ismcts,
+}
+
+#[must_use]
+pub fn from_options(options: Options, human: bool) -> Box<dyn PlayerStrategy> {
+    let attack = options.attack && !human;
+    let strategy: Box<dyn PlayerStrategy> = if human && options.random {
+        Box::new(RandomStrategy::new(attack))
+    } else if human && !options.test {
+        Box::new(StdinStrategy::new(options.quiet))
+    } else {
+        match options.bot {
+            Bot::Random => Box::new(RandomStrategy::new(attack)),
+            Bot::Heuristic => Box::new(HeuristicStrategy::new(attack)),
+            Bot::Ismcts => Box::new(IsmctsStrategy::new(attack, options.budget)),
+        }
+    };
+    if options.auto {
+        Box::new(AutoStrategy::new(strategy))
+    } else {
+        strategy
+    }
+}
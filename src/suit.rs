@@ -1,10 +1,10 @@
 use colored::{ColoredString, Colorize};
 use std::fmt;
 use std::str::FromStr;
-use strum::EnumIter;
+use strum::{EnumIter, IntoEnumIterator};
 
 use crate::errors::TarotErrorKind;
-use crate::traits::Representation;
+use crate::traits::{Code, Representation};
 
 #[derive(Copy, Clone, Debug, Eq, PartialEq, Ord, PartialOrd, EnumIter)]
 pub enum Suit {
@@ -48,6 +48,23 @@ impl Representation for Suit {
     }
 }
 
+impl Code for Suit {
+    fn code(&self) -> String {
+        let code = match self {
+            Self::Heart => "H",
+            Self::Spade => "S",
+            Self::Diamond => "D",
+            Self::Club => "C",
+        };
+        code.to_string()
+    }
+    fn from_code(code: &str) -> Result<Self, TarotErrorKind> {
+        Self::iter()
+            .find(|suit| suit.code() == code)
+            .ok_or(TarotErrorKind::InvalidColor)
+    }
+}
+
 impl FromStr for Suit {
     type Err = TarotErrorKind;
     fn from_str(s: &str) -> Result<Self, TarotErrorKind> {
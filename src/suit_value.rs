@@ -1,10 +1,10 @@
-use crate::points::Points;
-use crate::traits::{Discardable, Representation};
+use crate::errors::TarotErrorKind;
+use crate::points::{HalfPoints, Points};
+use crate::traits::{Code, Discardable, Representation};
 use colored::{ColoredString, Colorize};
 use indoc::indoc;
-use ordered_float::OrderedFloat;
 use std::fmt;
-use strum::EnumIter;
+use strum::{EnumIter, IntoEnumIterator};
 
 #[derive(Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd, EnumIter)]
 pub enum SuitValue {
@@ -42,12 +42,12 @@ impl Discardable for SuitValue {
 }
 
 impl Points for SuitValue {
-    fn points(&self) -> OrderedFloat<f64> {
-        let points = match self {
-            Self::Jack => 1.5,
-            Self::Knight => 2.5,
-            Self::Queen => 3.5,
-            Self::King => 4.5,
+    fn points(&self) -> HalfPoints {
+        let halves = match self {
+            Self::Jack => 3,
+            Self::Knight => 5,
+            Self::Queen => 7,
+            Self::King => 9,
             Self::_1
             | Self::_2
             | Self::_3
@@ -57,9 +57,35 @@ impl Points for SuitValue {
             | Self::_7
             | Self::_8
             | Self::_9
-            | Self::_10 => 0.5,
+            | Self::_10 => 1,
         };
-        OrderedFloat(points)
+        HalfPoints::from_halves(halves)
+    }
+}
+
+impl Code for SuitValue {
+    fn code(&self) -> String {
+        match self {
+            Self::Jack => "J".to_string(),
+            Self::Knight => "N".to_string(),
+            Self::Queen => "Q".to_string(),
+            Self::King => "K".to_string(),
+            Self::_1
+            | Self::_2
+            | Self::_3
+            | Self::_4
+            | Self::_5
+            | Self::_6
+            | Self::_7
+            | Self::_8
+            | Self::_9
+            | Self::_10 => (*self as usize).to_string(),
+        }
+    }
+    fn from_code(code: &str) -> Result<Self, TarotErrorKind> {
+        Self::iter()
+            .find(|value| value.code() == code)
+            .ok_or(TarotErrorKind::InvalidCard)
     }
 }
 
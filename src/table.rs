@@ -0,0 +1,103 @@
+use std::fmt;
+
+use crate::card::Card;
+use crate::deck::Deck;
+use crate::errors::TarotErrorKind;
+
+/// Cards laid in front of a player of a two players deal, each face up card covers a face down one
+#[derive(Default, Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Table {
+    // the face up card, and the face down card under it
+    piles: Vec<(Card, Option<Card>)>,
+}
+
+impl Table {
+    // RULE: the first half of the cards is laid face down, the second half covers it face up
+    pub fn lay(cards: &Deck) -> Result<Self, TarotErrorKind> {
+        if !cards.len().is_multiple_of(2) {
+            return Err(TarotErrorKind::InvalidDeck(cards.clone()));
+        }
+        let (hidden, shown) = cards.split_at(cards.len() / 2);
+        Ok(Self {
+            piles: shown
+                .iter()
+                .zip(hidden)
+                .map(|(shown, hidden)| (*shown, Some(*hidden)))
+                .collect(),
+        })
+    }
+    // face up cards, playable as if they were in hand
+    #[must_use]
+    pub fn shown(&self) -> Deck {
+        Deck::new(self.piles.iter().map(|(shown, _)| *shown).collect())
+    }
+    #[must_use]
+    pub fn shows(&self, card: &Card) -> bool {
+        self.piles.iter().any(|(shown, _)| shown == card)
+    }
+    // face down cards, unknown to everyone
+    #[must_use]
+    pub fn hidden(&self) -> Deck {
+        Deck::new(
+            self.piles
+                .iter()
+                .filter_map(|(_, hidden)| *hidden)
+                .collect(),
+        )
+    }
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.piles.len() + self.hidden().len()
+    }
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.piles.is_empty()
+    }
+    // RULE: a face down card is turned up once the card covering it is played
+    pub fn play(&mut self, card: &Card) -> Option<Card> {
+        let index = self.piles.iter().position(|(shown, _)| shown == card)?;
+        let (_, hidden) = self.piles.remove(index);
+        let uncovered = hidden?;
+        self.piles.insert(index, (uncovered, None));
+        Some(uncovered)
+    }
+}
+
+impl fmt::Display for Table {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for (shown, hidden) in &self.piles {
+            if hidden.is_some() {
+                write!(f, "{shown}/? ")?;
+            } else {
+                write!(f, "{shown} ")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[test]
+fn table_tests() -> Result<(), TarotErrorKind> {
+    use crate::suit::Suit;
+    use crate::suit_value::SuitValue;
+    use crate::trump::Trump;
+
+    let petit = Card::Trump(Trump::Petit);
+    let king = Card::normal(Suit::Heart, SuitValue::King);
+    let fool = Card::Trump(Trump::Fool);
+    let ten = Card::normal(Suit::Club, SuitValue::_10);
+    let mut table = Table::lay(&Deck::new(vec![petit, king, fool, ten]))?;
+    assert_eq!(table.len(), 4);
+    assert_eq!(table.shown(), Deck::new(vec![fool, ten]));
+    assert!(!table.shows(&petit));
+
+    assert_eq!(table.play(&fool), Some(petit));
+    assert!(table.shows(&petit));
+    assert_eq!(table.hidden(), Deck::new(vec![king]));
+    assert_eq!(table.play(&petit), None);
+    assert_eq!(table.play(&king), None);
+    assert_eq!(table.len(), 2);
+    assert!(Table::lay(&Deck::new(vec![petit])).is_err());
+    Ok(())
+}
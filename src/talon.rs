@@ -0,0 +1,69 @@
+use std::fmt;
+
+use crate::deck::Deck;
+use crate::errors::TarotErrorKind;
+
+// RULE: the talon is shown in two halves of three cards
+const HALF: usize = 3;
+
+/// The six cards of a Königrufen deal left aside, the declarer may take one of its halves
+#[derive(Default, Clone, Debug, Eq, PartialEq)]
+pub struct Talon {
+    halves: Vec<Deck>,
+}
+
+impl Talon {
+    pub fn new(cards: &Deck) -> Result<Self, TarotErrorKind> {
+        if cards.len() != 2 * HALF {
+            return Err(TarotErrorKind::InvalidDeck(cards.clone()));
+        }
+        Ok(Self {
+            halves: cards
+                .chunks(HALF)
+                .map(|half| Deck::new(half.to_vec()))
+                .collect(),
+        })
+    }
+    #[must_use]
+    pub fn halves(&self) -> &[Deck] {
+        &self.halves
+    }
+    // the chosen half goes to the declarer, the rest stays in the talon
+    pub fn take(&mut self, index: usize) -> Result<Deck, TarotErrorKind> {
+        if index >= self.halves.len() {
+            return Err(TarotErrorKind::IllegalChoice(format!(
+                "talon half {index} does not exist"
+            )));
+        }
+        Ok(self.halves.remove(index))
+    }
+    #[must_use]
+    pub fn cards(&self) -> Deck {
+        let mut cards = Deck::default();
+        for half in &self.halves {
+            cards.extend(half);
+        }
+        cards
+    }
+}
+
+impl fmt::Display for Talon {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for (index, half) in self.halves.iter().enumerate() {
+            writeln!(f, "Half {index} : {half}")?;
+        }
+        Ok(())
+    }
+}
+
+#[test]
+fn talon_tests() -> Result<(), TarotErrorKind> {
+    let mut talon = Talon::new(&Deck::tarock().give(2 * HALF))?;
+    assert_eq!(talon.halves().len(), 2);
+    let half = talon.take(1)?;
+    assert_eq!(half.len(), HALF);
+    assert_eq!(talon.cards().len(), HALF);
+    assert!(talon.take(1).is_err());
+    assert!(Talon::new(&Deck::tarock().give(HALF)).is_err());
+    Ok(())
+}
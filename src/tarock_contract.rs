@@ -0,0 +1,38 @@
+use strum::{Display, EnumIter};
+
+/// Games a Königrufen player can announce, from the lowest to the highest
+#[derive(Display, Copy, Clone, Debug, Eq, Ord, PartialEq, PartialOrd, EnumIter)]
+pub enum TarockContract {
+    Rufer,
+    Bettler,
+    Dreier,
+    Solo,
+}
+
+impl TarockContract {
+    // RULE: games are worth 1 for a rufer, 3 for a bettler, 4 for a dreier and 6 for a solo
+    #[must_use]
+    pub const fn value(self) -> i32 {
+        match self {
+            Self::Rufer => 1,
+            Self::Bettler => 3,
+            Self::Dreier => 4,
+            Self::Solo => 6,
+        }
+    }
+    // RULE: only a rufer calls a king to find a partner
+    #[must_use]
+    pub const fn calls(self) -> bool {
+        matches!(self, Self::Rufer)
+    }
+    // RULE: a rufer or a dreier takes a half of the talon, the other games leave it to the defense
+    #[must_use]
+    pub const fn uses_talon(self) -> bool {
+        matches!(self, Self::Rufer | Self::Dreier)
+    }
+    // RULE: the bettler must lose every trick
+    #[must_use]
+    pub const fn is_negative(self) -> bool {
+        matches!(self, Self::Bettler)
+    }
+}
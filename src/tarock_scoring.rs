@@ -0,0 +1,108 @@
+use crate::card::Card;
+use crate::deck::Deck;
+use crate::suit_value::SuitValue;
+use crate::tarock_contract::TarockContract;
+use crate::trump::Trump;
+
+// the 70 points of the 54 cards, counted in thirds
+pub const TOTAL_THIRDS: i32 = 210;
+// RULE: a side winning every trick makes a valat, the game counts four times
+const VALAT_MULTIPLIER: i32 = 4;
+
+// RULE: kings and the three honours are worth 5, queens 4, knights 3, jacks 2 and the other cards 1
+#[must_use]
+pub const fn value(card: Card) -> i32 {
+    match card {
+        Card::Trump(Trump::Fool | Trump::Petit | Trump::_21) => 5,
+        Card::Trump(_) => 1,
+        Card::Normal(normal) => match normal.value() {
+            SuitValue::King => 5,
+            SuitValue::Queen => 4,
+            SuitValue::Knight => 3,
+            SuitValue::Jack => 2,
+            SuitValue::_1
+            | SuitValue::_2
+            | SuitValue::_3
+            | SuitValue::_4
+            | SuitValue::_5
+            | SuitValue::_6
+            | SuitValue::_7
+            | SuitValue::_8
+            | SuitValue::_9
+            | SuitValue::_10 => 1,
+        },
+    }
+}
+
+// RULE: cards are counted by three with two points off each packet, so each card loses two thirds
+#[must_use]
+pub fn thirds(cards: &Deck) -> i32 {
+    cards.iter().map(|card| 3 * value(*card) - 2).sum()
+}
+
+#[must_use]
+pub fn repr(thirds: i32) -> String {
+    match thirds % 3 {
+        0 => format!("{}", thirds / 3),
+        rest => format!("{} {}/3", thirds / 3, rest.abs()),
+    }
+}
+
+// RULE: the declarers win with more than half of the points, a bettler without any trick
+#[must_use]
+pub const fn won(contract: TarockContract, thirds: i32, tricks: usize) -> bool {
+    if contract.is_negative() {
+        tricks == 0
+    } else {
+        2 * thirds > TOTAL_THIRDS
+    }
+}
+
+// points each declarer wins from each defender, negative when the game is lost
+#[must_use]
+pub const fn points(contract: TarockContract, won: bool, valat: bool) -> i32 {
+    let value = if valat {
+        VALAT_MULTIPLIER * contract.value()
+    } else {
+        contract.value()
+    };
+    if won { value } else { -value }
+}
+
+// RULE: each defender pays the game to each declarer, so a side gets it once per opponent
+#[must_use]
+pub const fn payments(points: i32, declarers: i32, defenders: i32) -> (i32, i32) {
+    (defenders * points, -declarers * points)
+}
+
+#[test]
+fn tarock_scoring_tests() {
+    use crate::suit::Suit;
+
+    assert_eq!(thirds(&Deck::tarock()), TOTAL_THIRDS);
+    let king = Card::normal(Suit::Heart, SuitValue::King);
+    let pip = Card::normal(Suit::Club, SuitValue::_7);
+    let trump = Card::Trump(Trump::_12);
+    assert_eq!(thirds(&Deck::new(vec![king, pip, trump])), 3 * 7 - 6);
+    assert_eq!(repr(thirds(&Deck::new(vec![king, pip, trump]))), "5");
+    assert_eq!(repr(thirds(&Deck::new(vec![king]))), "4 1/3");
+    assert!(won(TarockContract::Rufer, 106, 4));
+    assert!(!won(TarockContract::Dreier, 105, 6));
+    assert!(!won(TarockContract::Bettler, 0, 1));
+    assert_eq!(points(TarockContract::Solo, false, false), -6);
+    assert_eq!(points(TarockContract::Rufer, true, true), 4);
+    // a partnered rufer, each declarer gets the game from both defenders
+    assert_eq!(
+        payments(points(TarockContract::Rufer, true, false), 2, 2),
+        (2, -2)
+    );
+    assert_eq!(
+        payments(points(TarockContract::Rufer, false, true), 2, 2),
+        (-8, 8)
+    );
+    // a solo declarer gets it from the three defenders
+    assert_eq!(
+        payments(points(TarockContract::Solo, true, false), 1, 3),
+        (18, -6)
+    );
+}
@@ -0,0 +1,49 @@
+use rand::rngs::StdRng;
+use std::fmt;
+
+use crate::deck::Deck;
+use crate::errors::TarotErrorKind;
+use crate::heuristic_strategy::HeuristicStrategy;
+use crate::options::Options;
+use crate::player::Player;
+use crate::player_in_game::PlayerInGame;
+use crate::random_strategy::RandomStrategy;
+use crate::stdin_strategy::StdinStrategy;
+use crate::strategy::Bot;
+use crate::tarock_contract::TarockContract;
+
+/// Decisions only taken in a Königrufen deal, the other ones go through the player strategy
+pub trait TarockStrategy: fmt::Debug {
+    /// Choose a Königrufen game among the ones still available, None means pass
+    fn tarock_bid(
+        &self,
+        player: &Player,
+        in_game: &PlayerInGame,
+        contracts: &[TarockContract],
+        rng: &mut StdRng,
+    ) -> Result<Option<TarockContract>, TarotErrorKind>;
+    /// Index of the half of the Königrufen talon to take
+    fn talon(
+        &self,
+        player: &Player,
+        in_game: &PlayerInGame,
+        halves: &[Deck],
+        rng: &mut StdRng,
+    ) -> Result<usize, TarotErrorKind>;
+}
+
+// the tree search bot has no Königrufen model, it bids and exchanges by the rule of thumb
+#[must_use]
+pub fn from_options(options: Options, human: bool) -> Box<dyn TarockStrategy> {
+    let attack = options.attack && !human;
+    if human && options.random {
+        Box::new(RandomStrategy::new(attack))
+    } else if human && !options.test {
+        Box::new(StdinStrategy::new(options.quiet))
+    } else {
+        match options.bot {
+            Bot::Random => Box::new(RandomStrategy::new(attack)),
+            Bot::Heuristic | Bot::Ismcts => Box::new(HeuristicStrategy::new(attack)),
+        }
+    }
+}
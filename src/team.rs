@@ -1,6 +1,7 @@
 use strum::Display;
 
 #[derive(Display, Eq, PartialEq, Copy, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Team {
     Defense,
     Attack,
@@ -0,0 +1,302 @@
+use array_init::try_array_init;
+use derive_new::new;
+use itertools::Itertools;
+use rand::seq::SliceRandom;
+use rand::{Rng, SeedableRng, rngs::StdRng};
+use std::cmp::Ordering;
+use std::fmt;
+
+use crate::errors::TarotErrorKind;
+use crate::family::Family;
+use crate::game::Game;
+use crate::mode::Mode;
+use crate::options::Options;
+use crate::player::Player;
+use crate::strategy;
+
+/// A player of a tournament, moving from table to table between rounds
+#[derive(new, Clone, Debug, Eq, PartialEq)]
+pub struct Entrant {
+    name: String,
+    human: bool,
+    #[new(default)]
+    points: u32,
+    #[new(default)]
+    score: i32,
+    #[new(default)]
+    tables: usize,
+}
+
+impl Entrant {
+    #[must_use]
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+    #[must_use]
+    pub const fn is_human(&self) -> bool {
+        self.human
+    }
+    #[must_use]
+    pub const fn points(&self) -> u32 {
+        self.points
+    }
+    #[must_use]
+    pub const fn score(&self) -> i32 {
+        self.score
+    }
+    #[must_use]
+    pub const fn tables(&self) -> usize {
+        self.tables
+    }
+}
+
+impl fmt::Display for Entrant {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{} : {} points (score: {}, tables: {})",
+            self.name, self.points, self.score, self.tables
+        )
+    }
+}
+
+// RULE: a tournament table is a whole game, nobody sits out at it
+#[must_use]
+pub fn supports(options: Options, mode: Mode) -> bool {
+    options.family == Family::French && mode != Mode::Six
+}
+
+pub fn launch(
+    mode: Mode,
+    options: Options,
+    entrants: usize,
+    rounds: usize,
+    deals: u64,
+    seed: u64,
+) -> Result<(), TarotErrorKind> {
+    if !supports(options, mode) {
+        return Err(TarotErrorKind::InvalidPlayers(mode.players().to_string()));
+    }
+    if entrants == 0 {
+        return Err(TarotErrorKind::InvalidPlayers(entrants.to_string()));
+    }
+    if mode == Mode::Two {
+        Tournament::<{ Mode::Two.players() }>::new(options, entrants, seed).start(rounds, deals)?;
+    } else if mode == Mode::Three {
+        Tournament::<{ Mode::Three.players() }>::new(options, entrants, seed)
+            .start(rounds, deals)?;
+    } else if mode == Mode::Four {
+        Tournament::<{ Mode::Four.players() }>::new(options, entrants, seed)
+            .start(rounds, deals)?;
+    } else if mode == Mode::Five {
+        Tournament::<{ Mode::Five.players() }>::new(options, entrants, seed)
+            .start(rounds, deals)?;
+    }
+    Ok(())
+}
+
+// random seatings drawn before each round, the one spreading players the most is kept
+const DRAWS: usize = 64;
+
+pub struct Tournament<const MODE: usize> {
+    options: Options,
+    entrants: Vec<Entrant>,
+    // how many times two entrants sat at the same table
+    met: Vec<Vec<u32>>,
+    rng: StdRng,
+}
+
+impl<const MODE: usize> Tournament<MODE> {
+    #[must_use]
+    pub fn new(options: Options, entrants: usize, seed: u64) -> Self {
+        Self {
+            options,
+            // RULE: the first entrant is the one at the keyboard, like South at a single table
+            entrants: (1..=entrants)
+                .map(|i| Entrant::new(format!("Player {i}"), i == 1))
+                .collect(),
+            met: vec![vec![0; entrants]; entrants],
+            rng: StdRng::seed_from_u64(seed),
+        }
+    }
+    #[must_use]
+    pub fn entrants(&self) -> &[Entrant] {
+        &self.entrants
+    }
+    fn met(&self, a: usize, b: usize) -> u32 {
+        self.met
+            .get(a)
+            .and_then(|met| met.get(b))
+            .copied()
+            .unwrap_or_default()
+    }
+    pub fn start(&mut self, rounds: usize, deals: u64) -> Result<(), TarotErrorKind> {
+        for round in 1..=rounds {
+            for seats in self.seating() {
+                self.play(&seats, deals)?;
+            }
+            if !self.options.quiet {
+                println!("Standings after round {round} :");
+                for (rank, entrant) in self.standings().iter().enumerate() {
+                    println!("\t{}. {entrant}", rank + 1);
+                }
+            }
+        }
+        Ok(())
+    }
+    // seats of each table, with the index of the entrant sitting there, None for a bot
+    pub fn seating(&mut self) -> Vec<[Option<usize>; MODE]> {
+        let mut best = self.draw();
+        for _ in 1..DRAWS {
+            let seating = self.draw();
+            if self.cost(&seating) < self.cost(&best) {
+                best = seating;
+            }
+        }
+        best
+    }
+    // RULE: partners and opponents of previous rounds are spread, meeting again costs more and more
+    fn cost(&self, seating: &[[Option<usize>; MODE]]) -> u32 {
+        seating
+            .iter()
+            .flat_map(|seats| seats.iter().flatten().tuple_combinations())
+            .map(|(&a, &b)| self.met(a, b).pow(2))
+            .sum()
+    }
+    // RULE: empty seats are filled by bots, spread over the tables
+    fn draw(&mut self) -> Vec<[Option<usize>; MODE]> {
+        let tables = self.entrants.len().div_ceil(MODE);
+        let bots = tables * MODE - self.entrants.len();
+        let mut left: Vec<usize> = (0..self.entrants.len()).collect();
+        left.shuffle(&mut self.rng);
+        let mut seating = Vec::new();
+        for table in 0..tables {
+            let seated = MODE - bots / tables - usize::from(table < bots % tables);
+            let mut at_table: Vec<usize> = Vec::new();
+            // each newcomer is the one who met the others at the table the least
+            while at_table.len() < seated
+                && let Some(position) = left.iter().position_min_by_key(|&&candidate| {
+                    at_table
+                        .iter()
+                        .map(|&other| self.met(candidate, other))
+                        .sum::<u32>()
+                })
+            {
+                at_table.push(left.remove(position));
+            }
+            let mut seats = [None; MODE];
+            for (seat, index) in seats.iter_mut().zip(at_table) {
+                *seat = Some(index);
+            }
+            seats.shuffle(&mut self.rng);
+            seating.push(seats);
+        }
+        seating
+    }
+    fn play(&mut self, seats: &[Option<usize>; MODE], deals: u64) -> Result<(), TarotErrorKind> {
+        let players = try_array_init(|seat| -> Result<Player, TarotErrorKind> {
+            let (name, human) = match seats.get(seat).copied().flatten() {
+                Some(index) => {
+                    let entrant = self
+                        .entrants
+                        .get(index)
+                        .ok_or(TarotErrorKind::NoPlayer(index))?;
+                    (entrant.name.clone(), entrant.human)
+                }
+                None => (format!("Bot {}", seat + 1), false),
+            };
+            Ok(Player::new(
+                name,
+                self.options,
+                strategy::from_options(self.options, human),
+            ))
+        })?;
+        let mut game = Game::<MODE>::with_players(self.options, self.rng.random(), players)?;
+        game.start(deals)?;
+        game.is_consistent()?;
+
+        let scores: Vec<i32> = game.players().iter().map(Player::score).collect();
+        for (seat, score) in seats.iter().zip(&scores) {
+            let Some(index) = *seat else {
+                continue;
+            };
+            // RULE: 2 ranking points for each player outscored at the table, 1 for a tie
+            let points = scores
+                .iter()
+                .map(|other| match score.cmp(other) {
+                    Ordering::Greater => 2,
+                    Ordering::Equal => 1,
+                    Ordering::Less => 0,
+                })
+                .sum::<u32>()
+                - 1;
+            let entrant = self
+                .entrants
+                .get_mut(index)
+                .ok_or(TarotErrorKind::NoPlayer(index))?;
+            entrant.points += points;
+            entrant.score += score;
+            entrant.tables += 1;
+            for other in seats.iter().flatten() {
+                if *other != index
+                    && let Some(met) = self.met.get_mut(index).and_then(|met| met.get_mut(*other))
+                {
+                    *met += 1;
+                }
+            }
+        }
+        Ok(())
+    }
+    // ranking points first, then the sum of scores
+    #[must_use]
+    pub fn standings(&self) -> Vec<&Entrant> {
+        self.entrants
+            .iter()
+            .sorted_by(|a, b| b.points.cmp(&a.points).then(b.score.cmp(&a.score)))
+            .collect()
+    }
+}
+
+#[test]
+fn tournament_tests() -> Result<(), TarotErrorKind> {
+    let options = Options {
+        random: true,
+        auto: true,
+        quiet: true,
+        test: true,
+        ..Options::default()
+    };
+    let mut tournament = Tournament::<{ Mode::Four.players() }>::new(options, 6, 42);
+    assert_eq!(
+        tournament
+            .entrants()
+            .iter()
+            .filter(|entrant| entrant.is_human())
+            .count(),
+        1
+    );
+    let seating = tournament.seating();
+    assert_eq!(seating.len(), 2);
+    for seats in &seating {
+        assert_eq!(seats.iter().flatten().count(), 3);
+    }
+    let mut seated: Vec<usize> = seating.iter().flatten().flatten().copied().collect();
+    seated.sort_unstable();
+    assert_eq!(seated, (0..6).collect::<Vec<_>>());
+
+    tournament.start(3, 1)?;
+    for entrant in tournament.entrants() {
+        assert_eq!(entrant.tables(), 3);
+        assert!(entrant.points() <= 3 * 2 * 3);
+    }
+    let standings = tournament.standings();
+    assert!(standings.windows(2).all(|w| match w {
+        [a, b] => a.points() >= b.points(),
+        _ => false,
+    }));
+    // nobody met the same players at every round
+    assert!((0..6).all(|a| (0..6).all(|b| tournament.met(a, b) < 3)));
+
+    assert!(launch(Mode::Four, options, 0, 1, 1, 42).is_err());
+    Ok(())
+}
@@ -1,5 +1,7 @@
 use colored::ColoredString;
 
+use crate::errors::TarotErrorKind;
+
 pub trait Power {
     fn power(&self) -> usize;
 }
@@ -16,3 +18,9 @@ pub trait Representation {
     fn repr(&self) -> ColoredString;
     fn full_repr(&self) -> ColoredString;
 }
+
+// stable ASCII encoding, unlike the representation it does not depend on the terminal
+pub trait Code: Sized {
+    fn code(&self) -> String;
+    fn from_code(code: &str) -> Result<Self, TarotErrorKind>;
+}
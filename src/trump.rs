@@ -1,10 +1,10 @@
-use crate::points::Points;
-use crate::traits::{Discardable, Representation};
+use crate::errors::TarotErrorKind;
+use crate::points::{HalfPoints, Points};
+use crate::traits::{Code, Discardable, Representation};
 use colored::{ColoredString, Colorize};
 use indoc::indoc;
-use ordered_float::OrderedFloat;
 use std::fmt;
-use strum::EnumIter;
+use strum::{EnumIter, IntoEnumIterator};
 
 #[derive(Hash, Copy, Clone, Debug, Eq, PartialEq, Ord, PartialOrd, EnumIter)]
 pub enum Trump {
@@ -57,9 +57,24 @@ impl Discardable for Trump {
 }
 
 impl Points for Trump {
-    fn points(&self) -> OrderedFloat<f64> {
-        let points = if self.is_oudler() { 4.5 } else { 0.5 };
-        OrderedFloat(points)
+    fn points(&self) -> HalfPoints {
+        let halves = if self.is_oudler() { 9 } else { 1 };
+        HalfPoints::from_halves(halves)
+    }
+}
+
+impl Code for Trump {
+    fn code(&self) -> String {
+        if *self == Self::Fool {
+            "F".to_string()
+        } else {
+            format!("T{}", *self as usize)
+        }
+    }
+    fn from_code(code: &str) -> Result<Self, TarotErrorKind> {
+        Self::iter()
+            .find(|trump| trump.code() == code)
+            .ok_or(TarotErrorKind::InvalidCard)
     }
 }
 
@@ -3,10 +3,14 @@ use crate::deck::Deck;
 use crate::traits::Representation;
 use std::fmt;
 
-#[derive(Debug, Default)]
+#[derive(Debug, Default, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Turn {
     pub master_index: Option<usize>,
     cards: Deck,
+    players: Vec<usize>,
+    // index of a fool winning the turn, the master card still rules what the others must play
+    fool_wins: Option<usize>,
 }
 
 impl Turn {
@@ -15,12 +19,43 @@ impl Turn {
         Deck::new(
             self.cards
                 .into_iter()
-                .filter(|card| card.is_fool())
+                .filter(|card| !card.is_fool())
                 .collect(),
         )
     }
-    pub fn put(&mut self, card: Card) {
+    #[must_use]
+    pub fn take_cards(self) -> Deck {
+        self.cards
+    }
+    // RULE: a card becomes master when it beats the current master, the fool never does
+    pub fn put(&mut self, player_index: usize, card: Card) {
         self.cards.push(card);
+        self.players.push(player_index);
+        if card.is_fool() {
+            return;
+        }
+        if self.master_card().is_none_or(|master| !master.master(card)) {
+            self.master_index = Some(self.cards.len() - 1);
+        }
+    }
+    // RULE: the fool of a team which won every other turn wins the last one
+    pub fn fool_wins(&mut self) {
+        self.fool_wins = self.cards.iter().position(|card| card.is_fool());
+    }
+    #[must_use]
+    pub const fn is_won_by_fool(&self) -> bool {
+        self.fool_wins.is_some()
+    }
+    pub fn plays(&self) -> impl Iterator<Item = (usize, Card)> + '_ {
+        self.players.iter().copied().zip(self.cards.iter().copied())
+    }
+    #[must_use]
+    pub fn leader(&self) -> Option<usize> {
+        self.players.first().copied()
+    }
+    #[must_use]
+    pub const fn cards(&self) -> &Deck {
+        &self.cards
     }
     #[must_use]
     pub const fn len(&self) -> usize {
@@ -44,6 +79,28 @@ impl Turn {
     pub fn master_card(&self) -> Option<&Card> {
         self.master_index.map(|index| self.cards.get(index))?
     }
+    #[must_use]
+    pub fn played_by(&self, card: &Card) -> Option<usize> {
+        self.cards
+            .iter()
+            .position(|played| played == card)
+            .and_then(|index| self.players.get(index))
+            .copied()
+    }
+    #[must_use]
+    pub fn master_player(&self) -> Option<usize> {
+        self.master_index
+            .and_then(|index| self.players.get(index))
+            .copied()
+    }
+    // player taking the turn, the owner of a winning fool or of the master card
+    #[must_use]
+    pub fn winner(&self) -> Option<usize> {
+        self.fool_wins
+            .or(self.master_index)
+            .and_then(|index| self.players.get(index))
+            .copied()
+    }
 }
 
 impl fmt::Display for Turn {
@@ -58,3 +115,64 @@ impl fmt::Display for Turn {
         Ok(())
     }
 }
+
+#[test]
+fn turn_tests() -> Result<(), crate::errors::TarotErrorKind> {
+    use crate::mode::Mode;
+    use crate::options::Options;
+    use crate::player_in_game::PlayerInGame;
+    use crate::suit::Suit;
+    use crate::suit_value::SuitValue;
+    use crate::trump::Trump;
+
+    let fool = Card::Trump(Trump::Fool);
+    let heart_5 = Card::normal(Suit::Heart, SuitValue::_5);
+    let heart_king = Card::normal(Suit::Heart, SuitValue::King);
+    let spade_3 = Card::normal(Suit::Spade, SuitValue::_3);
+    let trump_21 = Card::Trump(Trump::_21);
+    let options = Options {
+        quiet: true,
+        ..Options::default()
+    };
+
+    // the fool led on the last turn wins it, the next card gives the color
+    let mut turn = Turn::default();
+    turn.put(0, fool);
+    turn.fool_wins();
+    assert_eq!(turn.master_card(), None);
+    let mut second = PlayerInGame::new(Mode::Three, options, 1);
+    second.extend_hand(&Deck::new(vec![heart_5]));
+    assert_eq!(second.choices(&turn)?, vec![0]);
+    turn.put(1, heart_5);
+    let mut third = PlayerInGame::new(Mode::Three, options, 2);
+    third.extend_hand(&Deck::new(vec![trump_21, heart_king]));
+    assert_eq!(third.choices(&turn)?, vec![1]);
+    turn.put(2, heart_king);
+    assert_eq!(turn.master_player(), Some(2));
+    assert_eq!(turn.winner(), Some(0));
+
+    // the fool played after other cards still wins the last turn
+    let mut turn = Turn::default();
+    turn.put(0, spade_3);
+    turn.put(1, fool);
+    turn.fool_wins();
+    turn.put(2, trump_21);
+    assert_eq!(turn.master_card(), Some(&trump_21));
+    assert_eq!(turn.winner(), Some(1));
+
+    // otherwise the fool never takes the turn
+    let mut turn = Turn::default();
+    turn.put(0, fool);
+    turn.put(1, heart_5);
+    assert_eq!(turn.winner(), Some(1));
+
+    // a gathered turn keeps its cards except the fool
+    let mut turn = Turn::default();
+    turn.put(0, heart_5);
+    turn.put(1, fool);
+    turn.put(2, heart_king);
+    let cards = turn.take_cards_except_fool();
+    assert_eq!(cards.len(), 2);
+    assert!(!cards.has_fool());
+    Ok(())
+}